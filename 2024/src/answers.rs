@@ -0,0 +1,143 @@
+use crate::Part;
+use anyhow::*;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::result::Result::Ok;
+
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// FNV-1a hash of the puzzle input, stable across builds and platforms.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Missing,
+    Match,
+    Changed { expected: String },
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Missing => write!(f, "missing"),
+            Verdict::Match => write!(f, "ok"),
+            Verdict::Changed { expected } => write!(f, "changed, expected {}", expected),
+        }
+    }
+}
+
+/// Confirmed answers keyed by day, part and the hash of the input they were computed for.
+///
+/// The file holds one tab-separated `day part input-hash answer` record per line.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part, String), String>,
+}
+
+impl Answers {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err).with_context(|| format!("read {}", path.display())),
+        };
+        Self::parse(&contents).with_context(|| format!("parse {}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let [day, part, hash, answer] = line.splitn(4, '\t').collect::<Vec<_>>()[..] else {
+                bail!("line {}: expected 4 tab-separated fields", i + 1);
+            };
+            let day = day
+                .parse()
+                .with_context(|| format!("line {}: invalid day {}", i + 1, day))?;
+            let part = part.parse().with_context(|| format!("line {}", i + 1))?;
+            answers.insert((day, part, hash.to_string()), answer.to_string());
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string()).with_context(|| format!("write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part, input_hash: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input_hash.to_string()))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, input_hash: &str, answer: &str) {
+        self.answers
+            .insert((day, part, input_hash.to_string()), answer.to_string());
+    }
+
+    pub fn verify(&self, day: u8, part: Part, input_hash: &str, answer: &str) -> Verdict {
+        match self.get(day, part, input_hash) {
+            None => Verdict::Missing,
+            Some(expected) if expected == answer => Verdict::Match,
+            Some(expected) => Verdict::Changed {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day\tpart\tinput-hash\tanswer")?;
+        for ((day, part, hash), answer) in self.answers.iter() {
+            writeln!(f, "{}\t{}\t{}\t{}", day, part, hash, answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let mut answers = Answers::default();
+        answers.insert(15, Part::One, "00ff", "1497888");
+        answers.insert(23, Part::Two, "00ff", "co,de,ka,ta");
+
+        let answers = Answers::parse(&answers.to_string()).unwrap();
+        assert_eq!(answers.get(15, Part::One, "00ff"), Some("1497888"));
+        assert_eq!(answers.get(15, Part::One, "0fff"), None);
+
+        assert_eq!(
+            answers.verify(23, Part::Two, "00ff", "co,de,ka,ta"),
+            Verdict::Match
+        );
+        assert_eq!(answers.verify(23, Part::One, "00ff", "7"), Verdict::Missing);
+        assert_eq!(
+            answers.verify(15, Part::One, "00ff", "1497889"),
+            Verdict::Changed {
+                expected: "1497888".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use crate::memory::{track, AllocStats};
+use crate::runner::solve;
+use crate::{Part, Solver};
+use anyhow::*;
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::result::Result::Ok;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Stage {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Stage::Part1,
+            Part::Two => Stage::Part2,
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part 1"),
+            Stage::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Timing statistics of one stage, in nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort();
+
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        let percentile = |p: f64| {
+            let rank = (p * samples.len() as f64).ceil() as usize;
+            nanos(samples[rank.clamp(1, samples.len()) - 1])
+        };
+        let total: Duration = samples.iter().sum();
+
+        Self {
+            iterations: samples.len(),
+            min_ns: nanos(samples[0]),
+            median_ns: percentile(0.5),
+            p95_ns: percentile(0.95),
+            mean_ns: nanos(total / samples.len() as u32),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+    /// Allocations of one run, if they were counted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<AllocStats>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub measurements: Vec<Measurement>,
+}
+
+impl BenchReport {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents =
+            fs::read_to_string(path).with_context(|| format!("read {}", path.display()))?;
+        serde_json::from_str(&contents).with_context(|| format!("parse {}", path.display()))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(path, contents).with_context(|| format!("write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|m| m.day == day && m.stage == stage)
+    }
+}
+
+fn measure<F: FnMut() -> Result<()>>(options: BenchOptions, mut f: F) -> Result<Stats> {
+    for _ in 0..options.warmup {
+        f()?;
+    }
+
+    let mut samples = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations.max(1) {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(samples))
+}
+
+/// Measures parsing and every solved part of a day on the given input. The allocations are
+/// counted on the untimed run that precedes the measurements of each stage.
+pub fn bench_day(
+    solver: &dyn Solver,
+    input: &str,
+    options: BenchOptions,
+) -> Result<Vec<Measurement>> {
+    let day = solver.day();
+    let (parsed, memory) = track(|| solver.parse_input(input));
+    let parsed = parsed?;
+    let mut measurements = vec![Measurement {
+        day,
+        stage: Stage::Parse,
+        stats: measure(options, || {
+            black_box(solver.parse_input(black_box(input))?);
+            Ok(())
+        })?,
+        memory,
+    }];
+
+    for part in Part::iter() {
+        let (answer, memory) = track(|| solve(solver, &parsed, part));
+        if answer?.is_none() {
+            continue;
+        }
+        measurements.push(Measurement {
+            day,
+            stage: part.into(),
+            stats: measure(options, || {
+                black_box(solver.solve(black_box(&parsed), part)?);
+                Ok(())
+            })?,
+            memory,
+        });
+    }
+
+    Ok(measurements)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+    /// Relative change of the median in percent, positive when slower.
+    pub change: f64,
+    pub flagged: bool,
+}
+
+/// Compares medians of the measurements present in both reports; changes whose magnitude exceeds
+/// `threshold` percent are flagged.
+pub fn compare(baseline: &BenchReport, current: &BenchReport, threshold: f64) -> Vec<Comparison> {
+    current
+        .measurements
+        .iter()
+        .filter_map(|m| {
+            let base = baseline.get(m.day, m.stage)?;
+            let baseline_ns = base.stats.median_ns;
+            let current_ns = m.stats.median_ns;
+            let change =
+                (current_ns as f64 - baseline_ns as f64) / baseline_ns.max(1) as f64 * 100.0;
+            Some(Comparison {
+                day: m.day,
+                stage: m.stage,
+                baseline_ns,
+                current_ns,
+                change,
+                flagged: change.abs() > threshold,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = (1..=20).rev().map(Duration::from_nanos).collect();
+        let stats = Stats::from_samples(samples);
+        assert_eq!(
+            stats,
+            Stats {
+                iterations: 20,
+                min_ns: 1,
+                median_ns: 10,
+                p95_ns: 19,
+                mean_ns: 10,
+            }
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let report = |median_ns| BenchReport {
+            measurements: vec![Measurement {
+                day: 6,
+                stage: Stage::Part2,
+                stats: Stats {
+                    iterations: 1,
+                    min_ns: median_ns,
+                    median_ns,
+                    p95_ns: median_ns,
+                    mean_ns: median_ns,
+                },
+                memory: None,
+            }],
+        };
+
+        let comparisons = compare(&report(100), &report(105), 10.0);
+        assert_eq!(comparisons.len(), 1);
+        assert!(!comparisons[0].flagged);
+
+        let comparisons = compare(&report(100), &report(50), 10.0);
+        assert_eq!(comparisons[0].change, -50.0);
+        assert!(comparisons[0].flagged);
+
+        assert!(compare(&BenchReport::default(), &report(50), 10.0).is_empty());
+    }
+}
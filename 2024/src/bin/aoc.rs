@@ -0,0 +1,342 @@
+use adv_code_2024::bench::BenchOptions;
+use adv_code_2024::client::{Client, Config, Fetched};
+use adv_code_2024::generate::{find_generator, parse_size, DEFAULT_SEED, GENERATORS};
+use adv_code_2024::input::{InputSource, INPUT_ENV};
+use adv_code_2024::logging::{init_logging, LOG_ENV};
+use adv_code_2024::runner::{bench, confirm, find_solver, run_day, submit, verify};
+use adv_code_2024::scaffold::{new_day, InputShape};
+use adv_code_2024::summary::{print_summary, run_all, Format};
+use adv_code_2024::watch::{watch, WatchOptions};
+use adv_code_2024::{Part, Solver, SOLVERS};
+use anyhow::*;
+use clap::{Parser, Subcommand};
+use std::fs;
+use std::path::PathBuf;
+use std::result::Result::Ok;
+use std::str::FromStr;
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOCATOR: adv_code_2024::memory::CountingAllocator =
+    adv_code_2024::memory::CountingAllocator;
+
+#[derive(Clone, Copy, Debug)]
+enum Target {
+    All,
+    Day(u8),
+}
+
+impl FromStr for Target {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "all" => Ok(Target::All),
+            day => Ok(Target::Day(
+                day.parse()
+                    .with_context(|| format!("invalid day: {}", day))?,
+            )),
+        }
+    }
+}
+
+impl Target {
+    fn solvers(self) -> Result<Vec<&'static dyn Solver>> {
+        match self {
+            Target::All => Ok(SOLVERS.to_vec()),
+            Target::Day(day) => Ok(vec![find_solver(day)?]),
+        }
+    }
+
+    /// Solvers of the target, checking that a single-day input source is used for one day only.
+    fn solvers_for(self, source: &InputSource) -> Result<Vec<&'static dyn Solver>> {
+        let solvers = self.solvers()?;
+        ensure!(
+            solvers.len() == 1 || !source.is_single(),
+            "input {} can only be used for a single day",
+            source
+        );
+        Ok(solvers)
+    }
+}
+
+/// Runs the Advent of Code 2024 solutions.
+#[derive(Parser, Debug)]
+#[command(
+    name = "aoc",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Day number, or `all` to run every solved day in parallel and print a summary
+    #[arg(required = true)]
+    target: Option<Target>,
+
+    /// Run only the given part (1 or 2)
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Summary format of `all`: table, json or tsv
+    #[arg(long, default_value = "table")]
+    format: Format,
+
+    /// Input file, directory of `<day>.txt` files, `-` for stdin, or `gen[:SIZE[:SEED]]` for
+    /// synthetic inputs, e.g. `aoc bench 9 --input gen:1M`
+    #[arg(long, global = true, env = INPUT_ENV)]
+    input: Option<InputSource>,
+
+    /// Log directives, e.g. `debug`, `6=trace` or `15[part2]=trace`
+    #[arg(long, global = true, env = LOG_ENV)]
+    log: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Re-run days and compare their answers with the recorded ones
+    Verify {
+        /// Day number, or `all`
+        #[arg(default_value = "all")]
+        target: Target,
+    },
+    /// Record the current answers of a day as confirmed
+    Confirm {
+        day: u8,
+
+        /// Record only the given part (1 or 2)
+        #[arg(long)]
+        part: Option<Part>,
+    },
+    /// Measure parse and solve times over repeated runs
+    Bench {
+        /// Day number, or `all`
+        #[arg(default_value = "all")]
+        target: Target,
+
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Timed runs per stage
+        #[arg(short = 'n', long, default_value_t = 20)]
+        iterations: usize,
+
+        /// Save the results to a JSON file
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare the results with a previously saved JSON file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+
+        /// Flag median changes larger than this many percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download puzzle inputs into the input directory, skipping those already there
+    Fetch {
+        /// Day number, or `all`
+        target: Target,
+
+        /// Website to download from, overriding the config
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Submit the answer of a part and record the verdict in the submission history
+    Submit {
+        day: u8,
+
+        /// Part to submit (1 or 2)
+        part: Part,
+
+        /// Answer to submit instead of the computed one
+        #[arg(long)]
+        answer: Option<String>,
+
+        /// Website to submit to, overriding the config
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Write a synthetic input of a day, or list the input generators if no day is given
+    Generate {
+        day: Option<u8>,
+
+        /// Size of the input in the unit of the day, e.g. 1000, 10k or 1M
+        #[arg(long, value_parser = parse_size)]
+        size: Option<usize>,
+
+        /// Seed of the generator, the same seed giving the same input
+        #[arg(long, default_value_t = DEFAULT_SEED)]
+        seed: u64,
+
+        /// File to write the input to instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Rebuild and re-run a day whenever its module, `lib.rs`, input or examples change
+    Watch {
+        day: u8,
+
+        /// Run only the given part (1 or 2)
+        #[arg(long)]
+        part: Option<Part>,
+
+        /// Build with optimizations, slower to build but faster on the real input
+        #[arg(long)]
+        release: bool,
+    },
+    /// Generate the module, binary, input and example files of a new day
+    NewDay {
+        day: u8,
+
+        /// Example input to store and to guess the input shape from
+        #[arg(long)]
+        sample: Option<PathBuf>,
+
+        /// Parser skeleton to use: grid, numbers, sections or lines
+        #[arg(long)]
+        shape: Option<InputShape>,
+    },
+}
+
+fn parts(part: Option<Part>) -> Vec<Part> {
+    match part {
+        Some(part) => vec![part],
+        None => Part::iter().collect(),
+    }
+}
+
+fn client(base_url: Option<String>) -> Result<Client> {
+    let mut config = Config::load()?;
+    if let Some(base_url) = base_url {
+        config.base_url = base_url;
+    }
+    Ok(Client::new(config))
+}
+
+fn main() -> Result<()> {
+    #[cfg(feature = "count-allocs")]
+    ALLOCATOR.mark_installed();
+    let cli = Cli::parse();
+    init_logging(cli.log.as_deref())?;
+    let source = cli.input.unwrap_or_default();
+
+    match cli.command {
+        Some(Command::Verify { target }) => {
+            let all_good = verify(&target.solvers_for(&source)?, &source)?;
+            ensure!(all_good, "some answers changed or days failed");
+            Ok(())
+        }
+        Some(Command::Confirm { day, part }) => confirm(find_solver(day)?, &parts(part), &source),
+        Some(Command::Bench {
+            target,
+            warmup,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        }) => bench(
+            &target.solvers_for(&source)?,
+            &source,
+            BenchOptions { warmup, iterations },
+            save.as_deref(),
+            baseline.as_deref(),
+            threshold,
+        ),
+        Some(Command::Fetch { target, base_url }) => {
+            let InputSource::Dir(dir) = &source else {
+                bail!(
+                    "inputs can only be fetched into a directory, not {}",
+                    source
+                );
+            };
+            let mut client = client(base_url)?;
+            for solver in target.solvers()? {
+                match client.fetch_input(solver.day(), dir)? {
+                    Fetched::Cached(path) => println!("Cached {}", path.display()),
+                    Fetched::Downloaded(path) => println!("Downloaded {}", path.display()),
+                }
+            }
+            Ok(())
+        }
+        Some(Command::Submit {
+            day,
+            part,
+            answer,
+            base_url,
+        }) => submit(
+            find_solver(day)?,
+            part,
+            answer,
+            &source,
+            &mut client(base_url)?,
+        ),
+        Some(Command::Generate { day: None, .. }) => {
+            println!("{:>3}  {:<18}  {:>7}", "Day", "Size unit", "default");
+            for generator in GENERATORS {
+                println!(
+                    "{:>3}  {:<18}  {:>7}",
+                    generator.day, generator.unit, generator.default_size
+                );
+            }
+            Ok(())
+        }
+        Some(Command::Generate {
+            day: Some(day),
+            size,
+            seed,
+            output,
+        }) => {
+            let input = find_generator(day)?.input(size, seed)?;
+            match output {
+                Some(path) => {
+                    fs::write(&path, input).with_context(|| format!("write {}", path.display()))
+                }
+                None => {
+                    print!("{}", input);
+                    Ok(())
+                }
+            }
+        }
+        Some(Command::Watch { day, part, release }) => watch(
+            day,
+            &source,
+            WatchOptions {
+                parts: parts(part),
+                release,
+                log: cli.log,
+            },
+        ),
+        Some(Command::NewDay { day, sample, shape }) => {
+            let sample = sample
+                .map(|path| {
+                    fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))
+                })
+                .transpose()?;
+            for path in new_day(day, sample.as_deref(), shape)? {
+                println!("Wrote {}", path.display());
+            }
+            Ok(())
+        }
+        None => match cli.target.expect("target is required") {
+            Target::All => {
+                let rows = run_all(
+                    &Target::All.solvers_for(&source)?,
+                    &parts(cli.part),
+                    &source,
+                )?;
+                print_summary(&rows, cli.format)?;
+                let failed = rows
+                    .iter()
+                    .filter(|row| row.status.is_failure())
+                    .map(|row| format!("{}/{}", row.day, row.part))
+                    .collect::<Vec<_>>();
+                ensure!(failed.is_empty(), "failed parts: {}", failed.join(", "));
+                Ok(())
+            }
+            Target::Day(day) => run_day(find_solver(day)?, &parts(cli.part), &source),
+        },
+    }
+}
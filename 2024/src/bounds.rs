@@ -0,0 +1,243 @@
+use crate::{AbsoluteRectangle, Rectangle};
+use std::fmt::Debug;
+
+/// Coordinates of [`Bounds`], `usize` for [`Position`](crate::Position) and `isize` for
+/// [`AbsolutePosition`](crate::AbsolutePosition).
+pub trait Coordinate: Copy + Debug + Ord {
+    const ONE: Self;
+
+    fn saturating_add(self, other: Self) -> Self;
+    fn saturating_sub(self, other: Self) -> Self;
+    /// Number of values from `self` to `other`, both included, for `self <= other`.
+    fn span(self, other: Self) -> usize;
+}
+
+impl Coordinate for usize {
+    const ONE: Self = 1;
+
+    fn saturating_add(self, other: Self) -> Self {
+        usize::saturating_add(self, other)
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        usize::saturating_sub(self, other)
+    }
+
+    fn span(self, other: Self) -> usize {
+        other - self + 1
+    }
+}
+
+impl Coordinate for isize {
+    const ONE: Self = 1;
+
+    fn saturating_add(self, other: Self) -> Self {
+        isize::saturating_add(self, other)
+    }
+
+    fn saturating_sub(self, other: Self) -> Self {
+        isize::saturating_sub(self, other)
+    }
+
+    fn span(self, other: Self) -> usize {
+        other.abs_diff(self) + 1
+    }
+}
+
+/// Non-empty rectangle of `(row, column)` positions between two corners, both included, like
+/// [`Rectangle`] and [`AbsoluteRectangle`] which convert to and from it.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Bounds<T> {
+    pub min: (T, T),
+    pub max: (T, T),
+}
+
+impl<T: Coordinate> Bounds<T> {
+    /// Bounds between two opposite corners, in any order.
+    pub fn new(a: (T, T), b: (T, T)) -> Self {
+        Self {
+            min: (a.0.min(b.0), a.1.min(b.1)),
+            max: (a.0.max(b.0), a.1.max(b.1)),
+        }
+    }
+
+    /// Smallest bounds containing all the points, `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = (T, T)>) -> Option<Self> {
+        points
+            .into_iter()
+            .map(|point| Self::new(point, point))
+            .reduce(|a, b| a.union(&b))
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.min.0.span(self.max.0)
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.min.1.span(self.max.1)
+    }
+
+    /// Number of positions.
+    pub fn area(&self) -> usize {
+        self.height() * self.width()
+    }
+
+    pub fn contains(&self, pos: (T, T)) -> bool {
+        pos.0 >= self.min.0 && pos.1 >= self.min.1 && pos.0 <= self.max.0 && pos.1 <= self.max.1
+    }
+
+    /// Positions in both bounds, `None` if they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = (self.min.0.max(other.min.0), self.min.1.max(other.min.1));
+        let max = (self.max.0.min(other.max.0), self.max.1.min(other.max.1));
+        (min.0 <= max.0 && min.1 <= max.1).then_some(Self { min, max })
+    }
+
+    /// Smallest bounds containing both.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: (self.min.0.min(other.min.0), self.min.1.min(other.min.1)),
+            max: (self.max.0.max(other.max.0), self.max.1.max(other.max.1)),
+        }
+    }
+
+    /// Grows by `n` on every side, stopping at the limits of the coordinates, e.g. 0 for `usize`.
+    pub fn expand(&self, n: T) -> Self {
+        Self {
+            min: (self.min.0.saturating_sub(n), self.min.1.saturating_sub(n)),
+            max: (self.max.0.saturating_add(n), self.max.1.saturating_add(n)),
+        }
+    }
+
+    /// Shrinks by `n` on every side, `None` if nothing is left.
+    pub fn shrink(&self, n: T) -> Option<Self> {
+        let min = (self.min.0.saturating_add(n), self.min.1.saturating_add(n));
+        let max = (self.max.0.saturating_sub(n), self.max.1.saturating_sub(n));
+        (min.0 <= max.0 && min.1 <= max.1).then_some(Self { min, max })
+    }
+
+    /// Closest position in the bounds.
+    pub fn clamp(&self, pos: (T, T)) -> (T, T) {
+        (
+            pos.0.clamp(self.min.0, self.max.0),
+            pos.1.clamp(self.min.1, self.max.1),
+        )
+    }
+
+    /// Positions row by row.
+    pub fn iter(&self) -> BoundsIter<T> {
+        BoundsIter {
+            bounds: *self,
+            next: Some(self.min),
+        }
+    }
+}
+
+impl<T: Coordinate> IntoIterator for &Bounds<T> {
+    type Item = (T, T);
+    type IntoIter = BoundsIter<T>;
+
+    fn into_iter(self) -> BoundsIter<T> {
+        self.iter()
+    }
+}
+
+/// Positions of [`Bounds`] row by row.
+#[derive(Clone, Debug)]
+pub struct BoundsIter<T> {
+    bounds: Bounds<T>,
+    next: Option<(T, T)>,
+}
+
+impl<T: Coordinate> Iterator for BoundsIter<T> {
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<(T, T)> {
+        let pos = self.next?;
+        let Bounds { min, max } = self.bounds;
+        self.next = if pos.1 < max.1 {
+            Some((pos.0, pos.1.saturating_add(T::ONE)))
+        } else if pos.0 < max.0 {
+            Some((pos.0.saturating_add(T::ONE), min.1))
+        } else {
+            None
+        };
+        Some(pos)
+    }
+}
+
+impl From<Rectangle> for Bounds<usize> {
+    fn from((a, b): Rectangle) -> Self {
+        Self::new(a, b)
+    }
+}
+
+impl From<Bounds<usize>> for Rectangle {
+    fn from(bounds: Bounds<usize>) -> Self {
+        (bounds.min, bounds.max)
+    }
+}
+
+impl From<AbsoluteRectangle> for Bounds<isize> {
+    fn from((a, b): AbsoluteRectangle) -> Self {
+        Self::new(a, b)
+    }
+}
+
+impl From<Bounds<isize>> for AbsoluteRectangle {
+    fn from(bounds: Bounds<isize>) -> Self {
+        (bounds.min, bounds.max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_and_iteration() {
+        let bounds = Bounds::<usize>::new((2, 3), (1, 1));
+        assert_eq!(bounds, Bounds::from(((1, 1), (2, 3))));
+        assert_eq!((bounds.height(), bounds.width(), bounds.area()), (2, 3, 6));
+        assert_eq!(
+            bounds.iter().collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (1, 3), (2, 1), (2, 2), (2, 3)]
+        );
+        assert!(bounds.contains((2, 3)));
+        assert!(!bounds.contains((0, 2)));
+
+        let bounds = Bounds::<isize>::new((-1, -1), (0, 0));
+        assert_eq!(bounds.iter().count(), 4);
+        assert_eq!(bounds.width(), 2);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = Bounds::<usize>::new((0, 0), (3, 3));
+        let b = Bounds::new((2, 1), (5, 2));
+        assert_eq!(a.intersection(&b), Some(Bounds::new((2, 1), (3, 2))));
+        assert_eq!(a.union(&b), Bounds::new((0, 0), (5, 3)));
+        assert_eq!(a.intersection(&Bounds::new((4, 4), (4, 4))), None);
+
+        assert_eq!(
+            Bounds::<isize>::from_points([(3, -2), (-1, 4), (0, 0)]),
+            Some(Bounds::new((-1, -2), (3, 4)))
+        );
+        assert_eq!(Bounds::<usize>::from_points([]), None);
+    }
+
+    #[test]
+    fn test_expand_shrink_clamp() {
+        let bounds = Bounds::<usize>::new((1, 2), (4, 4));
+        assert_eq!(bounds.expand(2), Bounds::new((0, 0), (6, 6)));
+        assert_eq!(bounds.shrink(1), Some(Bounds::new((2, 3), (3, 3))));
+        assert_eq!(bounds.shrink(2), None);
+        assert_eq!(bounds.clamp((0, 9)), (1, 4));
+        assert_eq!(bounds.clamp((3, 3)), (3, 3));
+
+        let bounds = Bounds::<isize>::new((0, 0), (1, 1));
+        assert_eq!(bounds.expand(1), Bounds::new((-1, -1), (2, 2)));
+    }
+}
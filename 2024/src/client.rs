@@ -0,0 +1,336 @@
+use crate::history::Outcome;
+use crate::input::crate_path;
+use crate::Part;
+use anyhow::*;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::result::Result::Ok;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Config file with `key: value` lines, kept out of version control.
+pub const CONFIG_FILE: &str = ".aoc-config";
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2024;
+
+const USER_AGENT: &str = concat!(
+    "adv-code-2024/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/alexandera5/aoc)"
+);
+
+/// Settings of the Advent of Code client.
+///
+/// Values come from the config file and are overridden by the `AOC_SESSION` and `AOC_BASE_URL`
+/// environment variables.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Minimum time between two requests
+    pub interval: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            interval: Duration::from_secs(3),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config file at the crate root, if any, and applies the environment overrides.
+    pub fn load() -> Result<Self> {
+        let path = crate_path(CONFIG_FILE);
+        let mut config = match fs::read_to_string(&path) {
+            Ok(contents) => {
+                Self::parse(&contents).with_context(|| format!("parse {}", path.display()))?
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => Self::default(),
+            Err(err) => return Err(err).with_context(|| format!("read {}", path.display())),
+        };
+
+        if let Ok(session) = std::env::var(SESSION_ENV) {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var(BASE_URL_ENV) {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut config = Self::default();
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once(':')
+                .with_context(|| format!("line {}: expected `key: value`", i + 1))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base-url" => config.base_url = value,
+                "interval" => {
+                    let secs = value
+                        .parse()
+                        .with_context(|| format!("line {}: invalid interval {}", i + 1, value))?;
+                    config.interval = Duration::from_secs_f64(secs)
+                }
+                key => bail!("line {}: unknown key {}", i + 1, key),
+            }
+        }
+        Ok(config)
+    }
+
+    fn session(&self) -> Result<&str> {
+        self.session.as_deref().with_context(|| {
+            format!(
+                "no session token, set {} or `session:` in {}",
+                SESSION_ENV, CONFIG_FILE
+            )
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Rate-limited client of the Advent of Code website.
+pub struct Client {
+    config: Config,
+    agent: ureq::Agent,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            last_request: None,
+        }
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!(
+            "{}/{}/day/{}{}",
+            self.config.base_url.trim_end_matches('/'),
+            YEAR,
+            day,
+            path
+        )
+    }
+
+    /// Waits until the configured interval since the previous request has passed.
+    fn throttle(&mut self) {
+        if let Some(last) = self.last_request {
+            if let Some(wait) = self.config.interval.checked_sub(last.elapsed()) {
+                thread::sleep(wait);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    /// Sends a request with the session cookie, posting the form if there is one.
+    fn request(&mut self, url: &str, form: Option<&[(&str, &str)]>) -> Result<String> {
+        let session = self.config.session()?.to_string();
+        self.throttle();
+        let method = if form.is_some() { "POST" } else { "GET" };
+        let request = self
+            .agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(404, _)) => bail!("{} not found, is the puzzle unlocked?", url),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                bail!("{} {} failed with {}: {}", method, url, code, body.trim())
+            }
+            Err(err) => Err(err).with_context(|| format!("{} {}", method, url)),
+        }
+    }
+
+    /// Downloads the input of a day into `<dir>/<day>.txt`, unless it is already there.
+    ///
+    /// An empty file, as created by `new-day`, does not count as cached.
+    pub fn fetch_input(&mut self, day: u8, dir: &Path) -> Result<Fetched> {
+        let path = dir.join(format!("{}.txt", day));
+        if fs::metadata(&path).is_ok_and(|metadata| metadata.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.request(&self.url(day, "/input"), None)?;
+        ensure!(!input.is_empty(), "empty input for day {}", day);
+
+        fs::create_dir_all(dir).with_context(|| format!("create {}", dir.display()))?;
+        let partial = path.with_extension("txt.part");
+        fs::write(&partial, input).with_context(|| format!("write {}", partial.display()))?;
+        fs::rename(&partial, &path).with_context(|| format!("write {}", path.display()))?;
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Submits the answer of a part and reads the verdict.
+    pub fn submit_answer(&mut self, day: u8, part: Part, answer: &str) -> Result<Outcome> {
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let page = self.request(&self.url(day, "/answer"), Some(&form))?;
+        Outcome::parse(&page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct Request {
+        line: String,
+        cookie: String,
+        body: String,
+    }
+
+    /// Serves canned responses on a local port, recording every request.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, Arc<Mutex<Vec<Request>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = requests.clone();
+        thread::spawn(move || {
+            for ((status, body), stream) in responses.into_iter().zip(listener.incoming()) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                let (mut cookie, mut length) = (String::new(), 0);
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(": ").unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = value.to_string(),
+                        "content-length" => length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+                let mut request_body = vec![0; length];
+                std::io::Read::read_exact(&mut reader, &mut request_body).unwrap();
+
+                recorded.lock().unwrap().push(Request {
+                    line: request_line.trim_end().to_string(),
+                    cookie,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (url, requests)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let config = Config::parse(
+            "# comment\nsession: 53616c\nbase-url: http://localhost\ninterval: 0.5\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                session: Some("53616c".to_string()),
+                base_url: "http://localhost".to_string(),
+                interval: Duration::from_millis(500),
+            }
+        );
+        assert!(Config::parse("token: 1").is_err());
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, requests) = mock_server(vec![(200, "1 2\n3 4\n"), (404, "")]);
+        let mut client = Client::new(Config {
+            session: Some("53616c".to_string()),
+            base_url,
+            interval: Duration::from_millis(200),
+        });
+        let dir = temp_dir("fetch");
+
+        let path = dir.join("1.txt");
+        assert_eq!(
+            client.fetch_input(1, &dir).unwrap(),
+            Fetched::Downloaded(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        assert_eq!(client.fetch_input(1, &dir).unwrap(), Fetched::Cached(path));
+
+        let start = Instant::now();
+        assert!(client.fetch_input(2, &dir).is_err());
+        assert!(start.elapsed() >= Duration::from_millis(150));
+        assert!(!dir.join("2.txt").exists());
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].line, "GET /2024/day/1/input HTTP/1.1");
+        assert_eq!(requests[0].cookie, "session=53616c");
+        assert!(requests[0].body.is_empty());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, requests) = mock_server(vec![(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )]);
+        let mut client = Client::new(Config {
+            session: Some("53616c".to_string()),
+            base_url,
+            interval: Duration::ZERO,
+        });
+
+        assert_eq!(
+            client.submit_answer(3, Part::Two, "1234").unwrap(),
+            Outcome::TooLow
+        );
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].line, "POST /2024/day/3/answer HTTP/1.1");
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+    }
+}
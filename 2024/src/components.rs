@@ -0,0 +1,215 @@
+use crate::bounds::Bounds;
+use crate::grid::Grid;
+use crate::Position;
+
+/// Neighbours through which the cells of a component connect.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Connectivity {
+    /// The 4 neighbours sharing a side
+    Four,
+    /// The 8 neighbours sharing a side or a corner
+    Eight,
+}
+
+/// Connected component of a grid and its geometry, the cells being unit squares.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Component {
+    /// First cell of the component row by row
+    pub start: Position,
+    /// Number of cells
+    pub area: usize,
+    /// Number of cell sides between the component and other components or the border of the grid
+    pub perimeter: usize,
+    /// Number of straight sides of the outlines, inner ones included, which is also their number
+    /// of corners
+    pub sides: usize,
+    pub bounds: Bounds<usize>,
+    /// Number of areas of other cells the component encloses, these cells connecting through the
+    /// neighbours the component does not, e.g. diagonally for a component in 4-connectivity
+    pub holes: usize,
+}
+
+/// Cells of a grid labelled by connected component, see [`Grid::components`].
+#[derive(Clone, Debug)]
+pub struct Components {
+    /// Index in `components` of the component of each cell
+    pub labels: Grid<usize>,
+    /// Components in the order of their first cell row by row
+    pub components: Vec<Component>,
+}
+
+impl Components {
+    /// Component of the cell at `pos`, `None` outside the grid.
+    pub fn component_at(&self, pos: Position) -> Option<&Component> {
+        self.labels.get(pos).map(|label| &self.components[*label])
+    }
+}
+
+/// Counts of the 2×2 windows, or bit quads, in which a component holds 1 cell, 3 cells or 2
+/// diagonal cells, from which its corners and Euler number follow.
+#[derive(Clone, Copy, Default)]
+struct Quads {
+    one: isize,
+    three: isize,
+    diagonal: isize,
+}
+
+impl<T> Grid<T> {
+    /// Labels the connected components, neighbours being in the same one when `same(cell,
+    /// neighbour)` holds.
+    pub fn components(
+        &self,
+        connectivity: Connectivity,
+        mut same: impl FnMut(&T, &T) -> bool,
+    ) -> Components {
+        let mut labels = self.map(|_| None);
+        let mut components = Vec::new();
+        let mut stack = Vec::new();
+
+        for (start, _) in self.iter() {
+            if labels[start].is_some() {
+                continue;
+            }
+
+            let label = components.len();
+            let mut component = Component {
+                start,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                bounds: Bounds::new(start, start),
+                holes: 0,
+            };
+            labels[start] = Some(label);
+            stack.push(start);
+            while let Some(pos) = stack.pop() {
+                component.area += 1;
+                component.bounds = component.bounds.union(&Bounds::new(pos, pos));
+                let cell = &self[pos];
+                for (next_pos, next) in self.neighbours8(pos) {
+                    let connected = connectivity == Connectivity::Eight
+                        || next_pos.0 == pos.0
+                        || next_pos.1 == pos.1;
+                    if connected && labels[next_pos].is_none() && same(cell, next) {
+                        labels[next_pos] = Some(label);
+                        stack.push(next_pos);
+                    }
+                }
+            }
+            components.push(component);
+        }
+
+        let labels = labels.map(|label| label.expect("every cell is labelled"));
+        for (pos, label) in labels.iter() {
+            // Sides on the border of the grid count towards the perimeter too
+            components[*label].perimeter += 4 - labels
+                .neighbours4(pos)
+                .filter(|(_, next)| *next == label)
+                .count();
+        }
+
+        let mut quads = vec![Quads::default(); components.len()];
+        let at = |i: Option<usize>, j: Option<usize>| labels.get((i?, j?)).copied();
+        for i in 0..=labels.height() {
+            for j in 0..=labels.width() {
+                // Cells around the top left corner of (i, j), clockwise
+                let (above, left) = (i.checked_sub(1), j.checked_sub(1));
+                let window = [
+                    at(above, left),
+                    at(above, Some(j)),
+                    at(Some(i), Some(j)),
+                    at(Some(i), left),
+                ];
+                for (k, label) in window.iter().enumerate() {
+                    let Some(label) = label else {
+                        continue;
+                    };
+                    if window[..k].contains(&Some(*label)) {
+                        continue;
+                    }
+                    let mask = window
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, other)| *other == &Some(*label))
+                        .fold(0_u8, |mask, (k, _)| mask | 1 << k);
+                    let quad = &mut quads[*label];
+                    match mask {
+                        0b0101 | 0b1010 => quad.diagonal += 1,
+                        mask if mask.count_ones() == 1 => quad.one += 1,
+                        mask if mask.count_ones() == 3 => quad.three += 1,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        for (component, quad) in components.iter_mut().zip(quads) {
+            component.sides = (quad.one + quad.three + 2 * quad.diagonal) as usize;
+            // Gray's formulas for the Euler number, 1 minus the number of holes
+            let euler = match connectivity {
+                Connectivity::Four => quad.one - quad.three + 2 * quad.diagonal,
+                Connectivity::Eight => quad.one - quad.three - 2 * quad.diagonal,
+            } / 4;
+            component.holes = (1 - euler) as usize;
+        }
+
+        Components { labels, components }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Input;
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::parse(&Input::new(input), Some, "").unwrap()
+    }
+
+    #[test]
+    fn test_four_connectivity() {
+        let grid = parse(".....\n.AAA.\n.A.A.\n.AA..\n.A.A.\n.AAA.\n.....\n");
+        let components = grid.components(Connectivity::Four, |a, b| a == b);
+        assert_eq!(components.components.len(), 4);
+        assert_eq!(
+            components.component_at((1, 1)),
+            Some(&Component {
+                start: (1, 1),
+                area: 12,
+                perimeter: 26,
+                sides: 16,
+                bounds: Bounds::new((1, 1), (5, 3)),
+                // The dots inside touch the outside through the corner at (3, 3)
+                holes: 0,
+            })
+        );
+        assert_eq!(components.labels[(0, 0)], 0);
+        assert_eq!(components.labels[(4, 2)], 3);
+        // The frame of dots encloses the A
+        assert_eq!(components.components[0].holes, 1);
+        assert_eq!(components.components[2].sides, 4);
+
+        let grid = parse("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO\n");
+        let components = grid.components(Connectivity::Four, |a, b| a == b);
+        let outer = &components.components[0];
+        assert_eq!((outer.area, outer.sides, outer.holes), (21, 20, 4));
+    }
+
+    #[test]
+    fn test_eight_connectivity() {
+        let grid = parse("a#b\n#.#\nc#d\n");
+        let components = grid.components(Connectivity::Eight, |a, b| a == b);
+        assert_eq!(components.components.len(), 6);
+        let diamond = components.component_at((0, 1)).unwrap();
+        assert_eq!(
+            (diamond.area, diamond.perimeter, diamond.sides),
+            (4, 16, 16)
+        );
+        assert_eq!(diamond.holes, 1);
+        assert_eq!(components.labels[(1, 1)], 3);
+
+        let components = grid.components(Connectivity::Four, |a, b| a == b);
+        assert_eq!(components.components.len(), 9);
+        assert!(components.components.iter().all(|c| c.holes == 0));
+    }
+}
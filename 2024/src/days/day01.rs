@@ -0,0 +1,62 @@
+use crate::input::Input;
+use crate::parser::Pattern;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use std::collections::HashMap;
+use std::result::Result::Ok;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = (Vec<usize>, Vec<usize>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        let pairs = Pattern::new("{}   {}").parse_lines::<(usize, usize)>(input.text())?;
+        Ok(pairs.into_iter().unzip())
+    }
+
+    fn part1((a_, b_): &Self::Input) -> Result<usize> {
+        let mut a_ = a_.clone();
+        let mut b_ = b_.clone();
+        a_.sort();
+        b_.sort();
+
+        let answer = a_.into_iter().zip(b_).map(|(a, b)| a.abs_diff(b)).sum();
+        Ok(answer)
+    }
+
+    fn part2((a_, b_): &Self::Input) -> Result<usize> {
+        let bm_ = b_.iter().fold(HashMap::<usize, usize>::new(), |mut m, x| {
+            *m.entry(*x).or_default() += 1;
+            m
+        });
+
+        let answer = a_.iter().map(|a| a * bm_.get(a).unwrap_or(&0)).sum();
+        Ok(answer)
+    }
+}
+
+impl Day01 {
+    /// Generates `size` pairs of location ids, with about a third of the right ones repeating
+    /// left ones so that the similarity score is not zero.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let left = (0..size)
+            .map(|_| rng.range(10000..=99999))
+            .collect::<Vec<_>>();
+        left.iter()
+            .map(|a| {
+                let b = if rng.chance(0.3) {
+                    *rng.choose(&left)
+                } else {
+                    rng.range(10000..=99999)
+                };
+                format!("{}   {}\n", a, b)
+            })
+            .collect()
+    }
+}
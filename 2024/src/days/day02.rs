@@ -0,0 +1,102 @@
+use crate::input::Input;
+use crate::parser::parse_list;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::result::Result::Ok;
+
+pub fn check_report_orig(record: &[i32]) -> bool {
+    let is_desc = record[0] > record[1];
+
+    for i in 0..(record.len() - 1) {
+        let a = record[i];
+        let b = record[i + 1];
+        if is_desc && a < b || !is_desc && a > b {
+            return false;
+        }
+
+        let diff = (a - b).abs();
+        if !(1..=3).contains(&diff) {
+            return false;
+        }
+    }
+    true
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<i32>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        input
+            .numbered_lines()
+            .map(|(i, line)| Ok(parse_list(line, ' ').map_err(|err| err.offset(i))?))
+            .collect()
+    }
+
+    fn part1(reports: &Self::Input) -> Result<usize> {
+        let answer = reports
+            .iter()
+            .map(|report| check_report_orig(report) as usize)
+            .sum::<usize>();
+        Ok(answer)
+    }
+
+    fn part2(reports: &Self::Input) -> Result<usize> {
+        let answer = reports
+            .iter()
+            .map(|report| {
+                if check_report_orig(report) {
+                    return 1;
+                }
+                for i in 0..report.len() {
+                    let mut changed_report = report.clone();
+                    changed_report.remove(i);
+                    if check_report_orig(&changed_report) {
+                        return 1;
+                    }
+                }
+                0
+            })
+            .sum::<usize>();
+        Ok(answer)
+    }
+}
+
+impl Day02 {
+    /// Generates `size` reports of 5 to 8 levels, monotonic steps of 1 to 3 with an occasional
+    /// unsafe one.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let increasing = rng.chance(0.5);
+                let mut level = if increasing {
+                    rng.range(1..=50)
+                } else {
+                    rng.range(50..=99)
+                };
+                let mut levels = vec![level];
+                for _ in 1..rng.range(5..=8) {
+                    let step = if rng.chance(0.1) {
+                        *rng.choose(&[0, 4, 5, 6])
+                    } else {
+                        rng.range(1..=3)
+                    };
+                    level = if increasing {
+                        level + step
+                    } else {
+                        level - step
+                    };
+                    levels.push(level);
+                }
+                format!("{}\n", levels.iter().join(" "))
+            })
+            .collect()
+    }
+}
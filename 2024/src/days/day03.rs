@@ -0,0 +1,100 @@
+use crate::input::Input;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use regex::Regex;
+use std::result::Result::Ok;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<usize> {
+        let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").expect("regex compilation");
+        let answer = lines
+            .iter()
+            .map(|line| {
+                re.captures_iter(line)
+                    .filter_map(|caps| {
+                        let (_, [a, b]) = caps.extract();
+                        match (a.parse::<usize>(), b.parse::<usize>()) {
+                            (Ok(a), Ok(b)) => Some(a * b),
+                            _ => None,
+                        }
+                    })
+                    .sum::<usize>()
+            })
+            .sum::<usize>();
+        Ok(answer)
+    }
+
+    fn part2(lines: &Self::Input) -> Result<usize> {
+        let re = Regex::new(r"(?<do>do\(\))|(?<dont>don't\(\))|mul\((?<a>\d{1,3}),(?<b>\d{1,3})\)")
+            .expect("regex compilation");
+
+        let mut enabled = true;
+        let answer = lines
+            .iter()
+            .map(|line| {
+                re.captures_iter(line)
+                    .filter_map(|caps| {
+                        if caps.name("do").is_some() {
+                            enabled = true;
+                            None
+                        } else if caps.name("dont").is_some() {
+                            enabled = false;
+                            None
+                        } else if let (Some(a), Some(b)) = (caps.name("a"), caps.name("b")) {
+                            match (a.as_str().parse::<usize>(), b.as_str().parse::<usize>()) {
+                                (Ok(a), Ok(b)) if enabled => Some(a * b),
+                                _ => None,
+                            }
+                        } else {
+                            None
+                        }
+                    })
+                    .sum::<usize>()
+            })
+            .sum::<usize>();
+        Ok(answer)
+    }
+}
+
+impl Day03 {
+    /// Generates `size` instructions, valid or corrupted, among junk characters.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let junk = [
+            '!', '@', '#', '$', '%', '^', '&', '*', '[', ']', '{', '}', '<', '>', ' ', ':',
+        ];
+        let mut text = String::new();
+        for i in 0..size {
+            let (a, b) = (rng.range(1..=999), rng.range(1..=999));
+            let instruction = match rng.range(0..=9) {
+                0 => "do()".to_string(),
+                1 => "don't()".to_string(),
+                2 => format!("mul({},{}]", a, b),
+                3 => format!("mul ( {} , {} )", a, b),
+                4 => format!("mul({}*{})", a, b),
+                _ => format!("mul({},{})", a, b),
+            };
+            text.push_str(&instruction);
+            for _ in 0..rng.range(0..=6) {
+                text.push(*rng.choose(&junk));
+            }
+            if i % 100 == 99 {
+                text.push('\n');
+            }
+        }
+        text.push('\n');
+        text
+    }
+}
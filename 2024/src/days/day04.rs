@@ -0,0 +1,150 @@
+use crate::input::Input;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use std::result::Result::Ok;
+use tracing::trace;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        Ok(input.grid()?)
+    }
+
+    fn part1(text: &Self::Input) -> Result<usize> {
+        let needle = ['X', 'M', 'A', 'S'];
+        let first_char = needle[0];
+
+        let area: Rectangle = ((0, 0), (text.len() - 1, text[0].len() - 1));
+
+        let answer = text
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                line.iter()
+                    .cloned()
+                    .enumerate()
+                    .filter(|(_, c)| *c == first_char)
+                    .map(|(j, _)| {
+                        Direction::iter()
+                            .filter(|dir| {
+                                let found = ray((i, j), **dir, area)
+                                    .with_max_len(needle.len())
+                                    .map(|bp| text[bp.0][bp.1])
+                                    .eq(needle);
+                                if found {
+                                    trace!("Found word at {:?} towards {:?}", (i, j), dir);
+                                }
+                                found
+                            })
+                            .count()
+                    })
+                    .sum::<usize>()
+            })
+            .sum();
+        Ok(answer)
+    }
+
+    fn part2(text: &Self::Input) -> Result<usize> {
+        let target_char = 'A';
+
+        let area: Rectangle = ((0, 0), (text.len() - 1, text[0].len() - 1));
+
+        let answer = text
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                line.iter()
+                    .cloned()
+                    .enumerate()
+                    .filter(|(_, c)| *c == target_char)
+                    .flat_map(|(j, _)| {
+                        let pos = (i, j);
+
+                        let ne = ray(pos, Direction::NE, area).nth(1)?;
+                        let sw = ray(pos, Direction::SW, area).nth(1)?;
+
+                        let nw = ray(pos, Direction::NW, area).nth(1)?;
+                        let se = ray(pos, Direction::SE, area).nth(1)?;
+
+                        let d1_match = text[ne.0][ne.1] == 'M' && text[sw.0][sw.1] == 'S'
+                            || text[ne.0][ne.1] == 'S' && text[sw.0][sw.1] == 'M';
+                        let d2_match = text[nw.0][nw.1] == 'M' && text[se.0][se.1] == 'S'
+                            || text[nw.0][nw.1] == 'S' && text[se.0][se.1] == 'M';
+
+                        if d1_match && d2_match {
+                            Some(1)
+                        } else {
+                            None
+                        }
+                    })
+                    .sum::<usize>()
+            })
+            .sum();
+        Ok(answer)
+    }
+}
+
+impl Day04 {
+    /// Generates a square word search of `size` letters per side.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let row = (0..size)
+                    .map(|_| *rng.choose(&['X', 'M', 'A', 'S']))
+                    .collect::<String>();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_beam() {
+        use super::{beam, Direction};
+
+        assert_eq!(
+            beam((4, 0), Direction::N, 4, (4, 4)),
+            Some(vec![(4, 0), (3, 0), (2, 0), (1, 0)])
+        );
+        assert_eq!(
+            beam((0, 1), Direction::S, 4, (4, 4)),
+            Some(vec![(0, 1), (1, 1), (2, 1), (3, 1)])
+        );
+
+        assert_eq!(
+            beam((0, 4), Direction::W, 4, (4, 4)),
+            Some(vec![(0, 4), (0, 3), (0, 2), (0, 1)])
+        );
+        assert_eq!(
+            beam((0, 1), Direction::E, 4, (4, 4)),
+            Some(vec![(0, 1), (0, 2), (0, 3), (0, 4)])
+        );
+
+        assert_eq!(
+            beam((4, 4), Direction::NW, 4, (4, 4)),
+            Some(vec![(4, 4), (3, 3), (2, 2), (1, 1)])
+        );
+        assert_eq!(
+            beam((4, 0), Direction::NE, 4, (4, 4)),
+            Some(vec![(4, 0), (3, 1), (2, 2), (1, 3)])
+        );
+
+        assert_eq!(
+            beam((9, 3), Direction::NW, 4, (9, 9)),
+            Some(vec![(9, 3), (8, 2), (7, 1), (6, 0)])
+        );
+
+        assert_eq!(beam((0, 0), Direction::N, 0, (4, 4)), Some(vec![]));
+        assert_eq!(beam((0, 0), Direction::N, 2, (4, 4)), None);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::input::Input;
+use crate::parser::{parse_list, Pattern};
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::result::Result::Ok;
+use tracing::{debug, trace};
+
+pub type Rules = HashMap<usize, HashSet<usize>>;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Rules, Vec<Vec<usize>>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        let [rules_section, updates_section] = &input.sections()[..] else {
+            bail!("expected rules and updates separated by a blank line");
+        };
+
+        let rule = Pattern::new("{}|{}");
+        let mut rules: Rules = HashMap::new();
+        for (i, line) in rules_section.numbered_lines() {
+            trace!("{}", line);
+            let (a, b) = rule
+                .parse::<(usize, usize)>(line)
+                .map_err(|err| err.offset(i))?;
+            rules.entry(a).or_default().insert(b);
+        }
+
+        let updates = updates_section
+            .numbered_lines()
+            .map(|(i, line)| {
+                trace!("{}", line);
+                parse_list(line, ',').map_err(|err| err.offset(i))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Result<usize> {
+        let answer = updates
+            .iter()
+            .filter_map(|update| {
+                let mut seen = HashSet::new();
+                for (i, c) in update.iter().enumerate() {
+                    seen.insert(*c);
+                    if i == 0 {
+                        continue;
+                    }
+
+                    if let Some(should_follow) = rules.get(c) {
+                        if should_follow.intersection(&seen).count() != 0 {
+                            debug!(
+                                "Update {:?} is invalid, expected  all of {:?} to follow {}",
+                                update, should_follow, c
+                            );
+                            return None;
+                        }
+                    }
+                }
+                Some(update[update.len() / 2])
+            })
+            .sum::<usize>();
+        Ok(answer)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Result<usize> {
+        let answer = updates
+            .iter()
+            .filter_map(|orig_update| {
+                let mut update = orig_update.clone();
+
+                let mut has_changes = false;
+                let mut seen = HashSet::new();
+                for i in 0..update.len() {
+                    let c = update[i];
+                    seen.insert(c);
+                    if i == 0 {
+                        continue;
+                    }
+
+                    if let Some(should_follow) = rules.get(&c) {
+                        if should_follow.intersection(&seen).count() != 0 {
+                            let mut k = i;
+                            for j in (0..i).rev() {
+                                let d = update[j];
+                                if should_follow.contains(&d) {
+                                    update.swap(k, j);
+                                    k -= 1;
+                                    trace!("Swap {} to {}: {:?}", c, d, update);
+                                    has_changes = true;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if has_changes {
+                    debug!("Update {:?} becomes {:?}", orig_update, update);
+                    Some(update[update.len() / 2])
+                } else {
+                    None
+                }
+            })
+            .sum::<usize>();
+        Ok(answer)
+    }
+}
+
+impl Day05 {
+    /// Generates rules ordering every pair of `size` pages, like the real input does, and `4 *
+    /// size` updates of an odd number of pages, half of them in the right order. There are at
+    /// least 2 pages so that there is a rule.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2);
+        let mut pages = (10..10 + size).collect::<Vec<_>>();
+        rng.shuffle(&mut pages);
+        let rank = pages
+            .iter()
+            .enumerate()
+            .map(|(i, page)| (*page, i))
+            .collect::<HashMap<_, _>>();
+
+        let mut text = String::new();
+        for (i, a) in pages.iter().enumerate() {
+            for b in &pages[i + 1..] {
+                text.push_str(&format!("{}|{}\n", a, b));
+            }
+        }
+        text.push('\n');
+
+        let longest = (size.min(23) - 1) / 2;
+        for _ in 0..4 * size {
+            let len = 2 * rng.range(0..=longest) + 1;
+            let mut update = HashSet::new();
+            while update.len() < len {
+                update.insert(*rng.choose(&pages));
+            }
+            let mut update = update.into_iter().collect::<Vec<_>>();
+            update.sort();
+            rng.shuffle(&mut update);
+            if rng.chance(0.5) {
+                update.sort_by_key(|page| rank[page]);
+            }
+            text.push_str(&format!("{}\n", update.iter().join(",")));
+        }
+        text
+    }
+}
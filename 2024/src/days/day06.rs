@@ -0,0 +1,229 @@
+use crate::grid::Grid;
+use crate::input::Input;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::result::Result::Ok;
+use tracing::{debug, trace, Span};
+
+/// Reads a map of `space` and the given tile symbols, failing on any other character.
+pub fn read_lab(input: &Input, space: char, symbols: &str) -> Result<Grid<char>> {
+    let expected = format!("one of {:?}", format!("{}{}", space, symbols));
+    let lab = Grid::parse(
+        input,
+        |c| (c == space || symbols.contains(c)).then_some(c),
+        &expected,
+    )?;
+    Ok(lab)
+}
+
+pub fn find_player(lab: &Grid<char>) -> Option<(Position, Cardinal)> {
+    lab.iter()
+        .find_map(|(pos, tile)| Cardinal::from_symbol(*tile).map(|dir| (pos, dir)))
+}
+
+pub fn walk(map: &Grid<char>, mut pos: Position, mut dir: Cardinal) -> (usize, bool) {
+    let mut visited_tiles = HashSet::new();
+    visited_tiles.insert(pos);
+
+    let mut visited_directions = HashSet::new();
+    visited_directions.insert((pos, dir));
+
+    trace!("Start walking pos={:?}, dir={:?}", pos, dir);
+
+    'walk: loop {
+        for new_pos in ray(pos, dir, map.area()).skip(1) {
+            trace!(
+                "Leaping at new_pos={:?}, dir={:?}, dist={}",
+                new_pos,
+                dir,
+                visited_tiles.len()
+            );
+
+            if visited_directions.contains(&(new_pos, dir)) {
+                return (visited_tiles.len(), true);
+            } else if map[new_pos] == '#' {
+                dir = dir.turn_right();
+                trace!(
+                    "Turn right at pos={:?}, dir={:?}, dist={}",
+                    pos,
+                    dir,
+                    visited_tiles.len()
+                );
+                continue 'walk;
+            }
+            pos = new_pos;
+            visited_tiles.insert(pos);
+            visited_directions.insert((pos, dir));
+        }
+
+        trace!(
+            "Leaped out of bounds after {} steps at pos={:?}",
+            visited_tiles.len(),
+            pos
+        );
+        return (visited_tiles.len(), false);
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = (Grid<char>, Position, Cardinal);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        let space = '.';
+        let mut map = read_lab(input, space, "#^>v<")?;
+        let (pos, dir) = find_player(&map).context("player not found")?;
+        map[pos] = space;
+        Ok((map, pos, dir))
+    }
+
+    fn part1((map, pos, dir): &Self::Input) -> Result<usize> {
+        let (total_dist, _) = walk(map, *pos, *dir);
+        Ok(total_dist)
+    }
+
+    fn part2((map, pos, dir): &Self::Input) -> Result<usize> {
+        debug!("Area {:?}", map.area());
+        // Rayon workers do not inherit the span of the part, which directives may filter on
+        let span = Span::current();
+        let answer = (0..map.height())
+            .into_par_iter()
+            .map(|i| {
+                (0..map.width())
+                    .into_par_iter()
+                    .filter(|j| map[(i, *j)] != '#')
+                    .filter(|j| {
+                        let _span = span.enter();
+                        let mut new_map = map.clone();
+                        new_map[(i, *j)] = '#';
+
+                        let (_, is_looping) = walk(&new_map, *pos, *dir);
+                        if is_looping {
+                            debug!("{:?} is looping", (i, *j));
+                        }
+                        is_looping
+                    })
+                    .count()
+            })
+            .sum();
+        Ok(answer)
+    }
+}
+
+impl Day06 {
+    /// Generates a square lab of `size` tiles per side, with one obstruction in 20 tiles and the
+    /// guard facing up on the tile, out of 20 random ones, from which it walks the longest.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut lab = Grid::new(size, size, '.');
+        for i in 0..size {
+            for j in 0..size {
+                if rng.chance(0.05) {
+                    lab[(i, j)] = '#';
+                }
+            }
+        }
+        let guard = (0..20)
+            .map(|_| (rng.range(0..=size - 1), rng.range(0..=size - 1)))
+            .filter(|pos| lab[*pos] != '#')
+            .max_by_key(|pos| walk(&lab, *pos, Cardinal::N).0)
+            .unwrap_or((size - 1, size - 1));
+        lab[guard] = '^';
+        lab.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_player, read_lab, walk};
+    use crate::input::Input;
+
+    const LOOP1: &str = r#"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#.#^.....
+........#.
+#.........
+......#..."#;
+
+    const LOOP2: &str = r#"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+......#.#.
+#.........
+......#..."#;
+
+    const LOOP3: &str = r#"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+.......##.
+#.........
+......#..."#;
+
+    const LOOP4: &str = r#"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+##........
+......#..."#;
+
+    const LOOP5: &str = r#"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#..#......
+......#..."#;
+
+    const LOOP6: &str = r#"....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#..#......
+......##.."#;
+
+    #[test]
+    fn test_walk() {
+        let space = '.';
+        for (i, input) in [LOOP1, LOOP2, LOOP3, LOOP4, LOOP5, LOOP6]
+            .iter()
+            .enumerate()
+        {
+            let mut map = read_lab(&Input::new(input), space, "#^>v<").expect("parse error");
+            let (pos, dir) = find_player(&map).expect("player not found");
+            map[pos] = space;
+
+            let (_, is_looping) = walk(&map, pos, dir);
+            assert!(is_looping, "Loop is not detected for {}", i + 1);
+        }
+    }
+}
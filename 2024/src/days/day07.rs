@@ -0,0 +1,257 @@
+use crate::input::Input;
+use crate::parser::{parse_list, Pattern};
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::result::Result::Ok;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Mul,
+    Add,
+    Concat,
+}
+
+fn concat(a: usize, b: usize) -> usize {
+    a * 10usize.pow(b.ilog10() + 1) + b
+}
+
+fn calculate(arguments: &[usize], operations: &[Operation]) -> usize {
+    let mut result = arguments[0];
+
+    for i in 0..operations.len() {
+        let arg = arguments[i + 1];
+        let op = &operations[i];
+
+        match op {
+            Operation::Mul => {
+                result *= arg;
+            }
+            Operation::Add => {
+                result += arg;
+            }
+            Operation::Concat => {
+                result = concat(result, arg);
+            }
+        }
+    }
+
+    result
+}
+
+fn product_recursive<T: Clone>(
+    alphabet: &[T],
+    n: usize,
+    current: &mut Vec<T>,
+    result: &mut Vec<Vec<T>>,
+) {
+    if current.len() == n {
+        result.push(current.to_vec());
+        return;
+    }
+
+    for c in alphabet.iter() {
+        current.push(c.clone());
+        product_recursive(alphabet, n, current, result);
+        current.pop();
+    }
+}
+
+fn product<T: Clone>(alphabet: &[T], n: usize) -> Vec<Vec<T>> {
+    let mut result = Vec::new();
+    let mut current = Vec::new();
+    product_recursive(alphabet, n, &mut current, &mut result);
+    result
+}
+
+pub fn solvable(result: usize, args: &[usize], op_alphabet: &[Operation]) -> bool {
+    product(op_alphabet, args.len() - 1)
+        .into_iter()
+        .any(|ops| calculate(args, &ops) == result)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<(usize, Vec<usize>)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        let equation = Pattern::new("{}: {}");
+        input
+            .numbered_lines()
+            .map(|(i, line)| {
+                let (result, args) = equation
+                    .parse::<(usize, String)>(line)
+                    .map_err(|err| err.offset(i))?;
+                let start = line.len() - args.len();
+                let args =
+                    parse_list(&args, ' ').map_err(|err| err.within(line, start).offset(i))?;
+                Ok((result, args))
+            })
+            .collect()
+    }
+
+    fn part1(equations: &Self::Input) -> Result<usize> {
+        let op_alphabet = [Operation::Add, Operation::Mul];
+        let answer = equations
+            .iter()
+            .filter(|(result, args)| solvable(*result, args, &op_alphabet))
+            .map(|(result, _)| result)
+            .sum::<usize>();
+        Ok(answer)
+    }
+
+    fn part2(equations: &Self::Input) -> Result<usize> {
+        let op_alphabet = [Operation::Add, Operation::Mul, Operation::Concat];
+        let answer = equations
+            .iter()
+            .filter(|(result, args)| solvable(*result, args, &op_alphabet))
+            .map(|(result, _)| result)
+            .sum::<usize>();
+        Ok(answer)
+    }
+}
+
+impl Day07 {
+    /// Generates `size` equations of 2 to 8 numbers below 100, half of them solvable with all
+    /// three operations; the results stay far below `usize::MAX`.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let args = (0..rng.range(2..=8))
+                    .map(|_| rng.range(1..=99))
+                    .collect::<Vec<_>>();
+                let result = if rng.chance(0.5) {
+                    args[1..]
+                        .iter()
+                        .fold(args[0], |result, arg| match rng.range(0..=2) {
+                            0 => result + arg,
+                            1 => result * arg,
+                            _ => result * 10usize.pow(arg.ilog10() + 1) + arg,
+                        })
+                } else {
+                    rng.range(1..=1_000_000_000_000)
+                };
+                format!("{}: {}\n", result, args.iter().join(" "))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{check, seed, Differential};
+    use itertools::Itertools;
+
+    /// Applies the operations left to right, trying every one at each step, the reference for
+    /// [`solvable`].
+    fn reaches(result: usize, acc: usize, args: &[usize], alphabet: &[Operation]) -> bool {
+        let Some((&arg, rest)) = args.split_first() else {
+            return acc == result;
+        };
+        alphabet.iter().any(|op| {
+            let acc = calculate(&[acc, arg], std::slice::from_ref(op));
+            reaches(result, acc, rest, alphabet)
+        })
+    }
+
+    /// Searching the operations recursively against enumerating every combination of them.
+    struct Operations;
+
+    impl Differential for Operations {
+        type Case = Vec<(usize, Vec<usize>)>;
+        type Answer = (usize, usize);
+
+        fn generate(rng: &mut Rng) -> Self::Case {
+            let alphabet = [Operation::Add, Operation::Mul, Operation::Concat];
+            (0..rng.range(1..=6))
+                .map(|_| {
+                    // Only the first argument may be 0, concatenating 0 being undefined
+                    let args = (0..rng.range(1..=5))
+                        .map(|k| rng.range(usize::from(k > 0)..=20))
+                        .collect_vec();
+                    let result = if rng.chance(0.5) {
+                        let ops = (1..args.len())
+                            .map(|_| rng.choose(&alphabet).clone())
+                            .collect_vec();
+                        calculate(&args, &ops)
+                    } else {
+                        rng.range(1..=2000)
+                    };
+                    (result, args)
+                })
+                .collect()
+        }
+
+        fn shrink(case: &Self::Case) -> Vec<Self::Case> {
+            let mut smaller = Vec::new();
+            for i in 0..case.len() {
+                smaller.push([&case[..i], &case[i + 1..]].concat());
+            }
+            for (i, (result, args)) in case.iter().enumerate() {
+                for j in (0..args.len()).filter(|_| args.len() > 1) {
+                    let mut case = case.clone();
+                    case[i] = (*result, [&args[..j], &args[j + 1..]].concat());
+                    smaller.push(case);
+                }
+            }
+            smaller
+        }
+
+        fn reference(case: &Self::Case) -> (usize, usize) {
+            let total = |alphabet: &[Operation]| {
+                case.iter()
+                    .filter(|(result, args)| reaches(*result, args[0], &args[1..], alphabet))
+                    .map(|(result, _)| result)
+                    .sum()
+            };
+            (
+                total(&[Operation::Add, Operation::Mul]),
+                total(&[Operation::Add, Operation::Mul, Operation::Concat]),
+            )
+        }
+
+        fn production(case: &Self::Case) -> (usize, usize) {
+            let text = case
+                .iter()
+                .map(|(result, args)| format!("{}: {}\n", result, args.iter().join(" ")))
+                .join("");
+            let equations = Day07::parse(&Input::new(&text)).unwrap();
+            (
+                Day07::part1(&equations).unwrap(),
+                Day07::part2(&equations).unwrap(),
+            )
+        }
+    }
+
+    #[test]
+    fn test_differential() {
+        if let Err(mismatch) = check::<Operations>(seed(), 500) {
+            panic!("{}", mismatch);
+        }
+    }
+
+    #[test]
+    fn test_calculate() {
+        assert_eq!(calculate(&[12, 345], &[Operation::Concat]), 12345);
+
+        assert_eq!(
+            calculate(&[17, 8, 14], &[Operation::Concat, Operation::Add]),
+            192,
+        );
+
+        assert_eq!(
+            calculate(
+                &[6, 8, 6, 15],
+                &[Operation::Mul, Operation::Concat, Operation::Mul]
+            ),
+            7290,
+        );
+    }
+}
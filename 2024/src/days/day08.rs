@@ -0,0 +1,329 @@
+use crate::bounds::Bounds;
+use crate::grid::Grid;
+use crate::input::Input;
+use crate::point::Point;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use std::collections::{HashMap, HashSet};
+use std::result::Result::Ok;
+
+/// Reads a map of `space` and antennas, which are letters and digits.
+pub fn read_roof(input: &Input, space: char) -> Result<Grid<Option<char>>> {
+    let expected = format!("antenna or {:?}", space);
+    let roof = Grid::parse(
+        input,
+        |c| match c {
+            _ if c == space => Some(None),
+            _ if c.is_ascii_alphanumeric() => Some(Some(c)),
+            _ => None,
+        },
+        &expected,
+    )?;
+    Ok(roof)
+}
+
+pub fn calc_antinode_locations(
+    s1: AbsolutePosition,
+    s2: AbsolutePosition,
+) -> Vec<AbsolutePosition> {
+    let (s1, s2) = (Point::from(s1), Point::from(s2));
+    let d = s2 - s1;
+
+    let locations = vec![(s1 - d).into(), (s2 + d).into()];
+    locations
+}
+
+/// Scans the whole area for the points on the line through both stations.
+pub fn calculate_line_points_in_area(
+    s1: AbsolutePosition,
+    s2: AbsolutePosition,
+    area: &AbsoluteRectangle,
+) -> Vec<AbsolutePosition> {
+    let (x1, y1) = s1;
+    let (x2, y2) = s2;
+
+    let a = y2 - y1;
+    let b = x1 - x2;
+    let c = x2 * y1 - x1 * y2;
+
+    // Keep the points (x, y) that satisfy the line equation
+    Bounds::from(*area)
+        .iter()
+        .filter(|(x, y)| a * x + b * y + c == 0)
+        .collect()
+}
+
+pub fn stations_by_name(map: &Grid<Option<char>>) -> HashMap<char, Vec<AbsolutePosition>> {
+    let mut stations_by_name: HashMap<char, Vec<_>> = HashMap::new();
+    map.iter().for_each(|((i, j), c)| {
+        if let Some(c) = c {
+            stations_by_name
+                .entry(*c)
+                .or_default()
+                .push((i as isize, j as isize));
+        }
+    });
+    stations_by_name
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Grid<Option<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        read_roof(input, '.')
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        let mut antinode_locations = HashSet::new();
+        stations_by_name(map).iter().for_each(|(_, ps)| {
+            for i in 0..ps.len() {
+                for j in 0..ps.len() {
+                    if i == j {
+                        continue;
+                    }
+
+                    calc_antinode_locations(ps[i], ps[j])
+                        .into_iter()
+                        .filter(|a| map.get_abs(*a).is_some())
+                        .for_each(|a| {
+                            antinode_locations.insert(a);
+                        });
+                }
+            }
+        });
+
+        let answer = antinode_locations.len();
+        Ok(answer)
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        let mut antinode_locations = HashSet::new();
+        stations_by_name(map).iter().for_each(|(_, ps)| {
+            for i in 0..ps.len() {
+                for j in 0..ps.len() {
+                    if i == j {
+                        continue;
+                    }
+
+                    antinode_locations.extend(calculate_line_points_in_area(
+                        ps[i],
+                        ps[j],
+                        &map.absolute_area(),
+                    ));
+                }
+            }
+        });
+
+        let answer = antinode_locations.len();
+        Ok(answer)
+    }
+}
+
+impl Day08 {
+    /// Generates a square roof of `size` tiles per side, with an antenna of one of 62
+    /// frequencies on one tile in 100.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let frequencies = ('0'..='9')
+            .chain('a'..='z')
+            .chain('A'..='Z')
+            .collect::<Vec<_>>();
+        (0..size)
+            .map(|_| {
+                let row = (0..size)
+                    .map(|_| {
+                        if rng.chance(0.01) {
+                            *rng.choose(&frequencies)
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{check, seed, Differential};
+
+    #[derive(Clone, Debug)]
+    struct Roof {
+        height: usize,
+        width: usize,
+        antennas: Vec<((usize, usize), char)>,
+    }
+
+    /// Checking every position against every pair of antennas against the pairwise antinode
+    /// logic.
+    struct Antinodes;
+
+    impl Differential for Antinodes {
+        type Case = Roof;
+        type Answer = (usize, usize);
+
+        fn generate(rng: &mut Rng) -> Roof {
+            let (height, width) = (rng.range(1..=12), rng.range(1..=12));
+            let mut antennas: Vec<((usize, usize), char)> = Vec::new();
+            for _ in 0..rng.range(0..=8) {
+                let pos = (rng.range(0..=height - 1), rng.range(0..=width - 1));
+                if antennas.iter().all(|(other, _)| *other != pos) {
+                    antennas.push((pos, *rng.choose(&['a', 'b', 'Z', '0'])));
+                }
+            }
+            Roof {
+                height,
+                width,
+                antennas,
+            }
+        }
+
+        fn shrink(roof: &Roof) -> Vec<Roof> {
+            let mut smaller = Vec::new();
+            for i in 0..roof.antennas.len() {
+                let mut roof = roof.clone();
+                roof.antennas.remove(i);
+                smaller.push(roof);
+            }
+            if roof.height > 1 {
+                let mut roof = roof.clone();
+                roof.height -= 1;
+                roof.antennas.retain(|((i, _), _)| *i < roof.height);
+                smaller.push(roof);
+            }
+            if roof.width > 1 {
+                let mut roof = roof.clone();
+                roof.width -= 1;
+                roof.antennas.retain(|((_, j), _)| *j < roof.width);
+                smaller.push(roof);
+            }
+            smaller
+        }
+
+        fn reference(roof: &Roof) -> (usize, usize) {
+            let pos = |(i, j): (usize, usize)| (i as isize, j as isize);
+            let pairs = roof
+                .antennas
+                .iter()
+                .flat_map(|a| roof.antennas.iter().map(move |b| (a, b)))
+                .filter(|((a, fa), (b, fb))| a != b && fa == fb)
+                .map(|((a, _), (b, _))| (pos(*a), pos(*b)))
+                .collect::<Vec<_>>();
+
+            let positions = (0..roof.height)
+                .flat_map(|i| (0..roof.width).map(move |j| pos((i, j))))
+                .collect::<Vec<_>>();
+            let part1 = positions
+                .iter()
+                .filter(|p| {
+                    pairs.iter().any(|(a, b)| {
+                        let (pa, pb) = ((a.0 - p.0, a.1 - p.1), (b.0 - p.0, b.1 - p.1));
+                        // In line and beyond b, with a twice as far as b
+                        let (da, db) = (pa.0 * pa.0 + pa.1 * pa.1, pb.0 * pb.0 + pb.1 * pb.1);
+                        pa.0 * pb.1 == pa.1 * pb.0 && da == 4 * db && pa.0 * pb.0 + pa.1 * pb.1 > 0
+                    })
+                })
+                .count();
+            let part2 = positions
+                .iter()
+                .filter(|p| {
+                    pairs.iter().any(|(a, b)| {
+                        // In line with both antennas
+                        (a.0 - p.0) * (b.1 - p.1) == (a.1 - p.1) * (b.0 - p.0)
+                    })
+                })
+                .count();
+            (part1, part2)
+        }
+
+        fn production(roof: &Roof) -> (usize, usize) {
+            let mut grid = vec![vec!['.'; roof.width]; roof.height];
+            for ((i, j), c) in roof.antennas.iter() {
+                grid[*i][*j] = *c;
+            }
+            let text = grid
+                .iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect::<String>();
+            let map = Day08::parse(&Input::new(&text)).unwrap();
+            (Day08::part1(&map).unwrap(), Day08::part2(&map).unwrap())
+        }
+    }
+
+    #[test]
+    fn test_differential() {
+        if let Err(mismatch) = check::<Antinodes>(seed(), 500) {
+            panic!("{}", mismatch);
+        }
+    }
+
+    #[test]
+    fn test_antinode_locations() {
+        assert_eq!(
+            calc_antinode_locations((3, 4), (5, 5)),
+            vec![(1, 3), (7, 6)]
+        );
+        assert_eq!(
+            calc_antinode_locations((5, 5), (3, 4)),
+            vec![(7, 6), (1, 3)]
+        );
+
+        assert_eq!(
+            calc_antinode_locations((3, 4), (4, 8)),
+            vec![(2, 0), (5, 12)]
+        );
+        assert_eq!(
+            calc_antinode_locations((4, 8), (3, 4)),
+            vec![(5, 12), (2, 0)]
+        );
+
+        assert_eq!(
+            calc_antinode_locations((4, 8), (5, 5)),
+            vec![(3, 11), (6, 2)]
+        );
+        assert_eq!(
+            calc_antinode_locations((5, 5), (4, 8)),
+            vec![(6, 2), (3, 11)]
+        );
+    }
+
+    #[test]
+    fn test_calculate_line_points_in_area() {
+        let area = ((0, 0), (9, 9));
+        assert_eq!(
+            calculate_line_points_in_area((0, 0), (2, 1), &area),
+            vec![(0, 0), (2, 1), (4, 2), (6, 3), (8, 4)]
+        );
+        assert_eq!(
+            calculate_line_points_in_area((2, 1), (0, 0), &area),
+            vec![(0, 0), (2, 1), (4, 2), (6, 3), (8, 4)]
+        );
+
+        assert_eq!(
+            calculate_line_points_in_area((0, 0), (1, 3), &area),
+            vec![(0, 0), (1, 3), (2, 6), (3, 9)]
+        );
+        assert_eq!(
+            calculate_line_points_in_area((1, 3), (0, 0), &area),
+            vec![(0, 0), (1, 3), (2, 6), (3, 9)]
+        );
+
+        assert_eq!(
+            calculate_line_points_in_area((1, 3), (2, 1), &area),
+            vec![(0, 5), (1, 3), (2, 1)]
+        );
+        assert_eq!(
+            calculate_line_points_in_area((2, 1), (1, 3), &area),
+            vec![(0, 5), (1, 3), (2, 1)]
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use crate::input::Input;
+use crate::parser::ParseError;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::result::Result::Ok;
+
+#[derive(Debug, Clone, Copy)]
+enum DiskEntry {
+    Block { file_id: u32, len: u8 },
+    Free { len: u8 },
+}
+
+/// Files and free space of a disk, as given by its compact map.
+///
+/// ```
+/// use adv_code_2024::day09::DiskMap;
+///
+/// let disk = DiskMap::from_compact_str("12345").unwrap();
+/// assert_eq!(disk.compacted().checksum(), 60);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DiskMap {
+    entries: Vec<DiskEntry>,
+}
+
+impl std::fmt::Display for DiskMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let repr = self
+            .entries
+            .iter()
+            .map(|entry| match entry {
+                DiskEntry::Block { file_id, len } => file_id.to_string().repeat(*len as usize),
+                DiskEntry::Free { len } => ".".repeat(*len as usize),
+            })
+            .join("");
+        write!(f, "{}", repr)
+    }
+}
+impl DiskMap {
+    pub fn from_compact_str(line: &str) -> Result<Self, ParseError> {
+        let mut file_id = 0;
+        let entries = line
+            .char_indices()
+            .enumerate()
+            .map(|(i, (offset, c))| {
+                let d = c
+                    .to_digit(10)
+                    .ok_or_else(|| ParseError::char_at(line, offset, "digit"))?;
+                if i % 2 != 0 {
+                    Ok(DiskEntry::Free { len: d as u8 })
+                } else {
+                    let entry = DiskEntry::Block {
+                        file_id,
+                        len: d as u8,
+                    };
+                    file_id += 1;
+                    Ok(entry)
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(DiskMap { entries })
+    }
+
+    pub fn checksum(&self) -> usize {
+        let mut block_id = 0usize;
+        self.entries
+            .iter()
+            .cloned()
+            .map(|entry| match entry {
+                DiskEntry::Block { file_id, len } => {
+                    let block_checksum: usize = (0usize..len as usize)
+                        .map(|i| (block_id + i) * file_id as usize)
+                        .sum();
+                    block_id += len as usize;
+                    block_checksum
+                }
+                DiskEntry::Free { len } => {
+                    block_id += len as usize;
+                    0usize
+                }
+            })
+            .sum()
+    }
+
+    pub fn compacted(mut self) -> Self {
+        let mut compacted_entries = vec![];
+        let mut entry_idx = 0;
+        let mut last_entry_idx = self.entries.len() - 1;
+
+        while entry_idx <= last_entry_idx {
+            let mid_idx = entry_idx + 1;
+            let (first_entries, last_entries) = self.entries.split_at_mut(mid_idx);
+
+            let entry = first_entries
+                .get_mut(entry_idx)
+                .expect("retrieve entry at index");
+            match entry {
+                DiskEntry::Block { file_id, len } if *len > 0 => {
+                    compacted_entries.push(DiskEntry::Block {
+                        file_id: *file_id,
+                        len: *len,
+                    });
+                    entry_idx += 1;
+                }
+                DiskEntry::Free {
+                    len: ref mut free_len,
+                } if *free_len > 0 => {
+                    while last_entry_idx >= mid_idx {
+                        match last_entries
+                            .get_mut(last_entry_idx - mid_idx)
+                            .expect("retrieve entry at index")
+                        {
+                            DiskEntry::Free { .. } => {
+                                last_entry_idx -= 1;
+                            }
+                            DiskEntry::Block {
+                                file_id: last_file_id,
+                                len: last_len,
+                            } => {
+                                if free_len >= last_len {
+                                    compacted_entries.push(DiskEntry::Block {
+                                        file_id: *last_file_id,
+                                        len: *last_len,
+                                    });
+                                    *free_len -= *last_len;
+                                    *last_len = 0;
+                                    last_entry_idx -= 1;
+                                    if *free_len == 0 {
+                                        break;
+                                    }
+                                } else {
+                                    compacted_entries.push(DiskEntry::Block {
+                                        file_id: *last_file_id,
+                                        len: *free_len,
+                                    });
+                                    *last_len -= *free_len;
+                                    *free_len = 0;
+                                    break;
+                                }
+                            }
+                        }
+                    }
+
+                    if *free_len == 0 || entry_idx == last_entry_idx {
+                        entry_idx += 1;
+                    }
+                }
+                _ => {
+                    entry_idx += 1;
+                }
+            }
+        }
+
+        DiskMap {
+            entries: compacted_entries,
+        }
+    }
+
+    pub fn defrag_compacted(mut self) -> Self {
+        for i in (0..self.entries.len()).rev() {
+            let DiskEntry::Block { len, .. } = self.entries[i] else {
+                continue;
+            };
+
+            for j in 0..i {
+                let DiskEntry::Free { len: free_len } = self.entries[j] else {
+                    continue;
+                };
+
+                match len.cmp(&free_len) {
+                    Ordering::Less => {
+                        self.entries[j] = DiskEntry::Free { len };
+                        self.entries.swap(i, j);
+                        self.entries.insert(
+                            j + 1,
+                            DiskEntry::Free {
+                                len: free_len - len,
+                            },
+                        );
+                        break;
+                    }
+                    Ordering::Equal => {
+                        self.entries.swap(i, j);
+                        break;
+                    }
+                    Ordering::Greater => {}
+                }
+            }
+        }
+
+        DiskMap {
+            entries: self.entries,
+        }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = DiskMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        let line = input.lines().next().context("empty input")?;
+        Ok(DiskMap::from_compact_str(line)?)
+    }
+
+    fn part1(disk_map: &Self::Input) -> Result<usize> {
+        Ok(disk_map.clone().compacted().checksum())
+    }
+
+    fn part2(disk_map: &Self::Input) -> Result<usize> {
+        Ok(disk_map.clone().defrag_compacted().checksum())
+    }
+}
+
+impl Day09 {
+    /// Generates a disk map of `size` digits, files never being empty.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut text = (0..size)
+            .map(|i| match i % 2 {
+                0 => char::from(b'0' + rng.range(1..=9) as u8),
+                _ => char::from(b'0' + rng.range(0..=9) as u8),
+            })
+            .collect::<String>();
+        text.push('\n');
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{check, seed, Differential};
+
+    /// Checksum of the disk, block by block.
+    fn checksum(blocks: &[Option<usize>]) -> usize {
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(i, block)| i * block.unwrap_or_default())
+            .sum()
+    }
+
+    /// Moving single blocks and whole files on the expanded disk against the entry-level
+    /// compaction.
+    struct Compaction;
+
+    impl Differential for Compaction {
+        type Case = Vec<u8>;
+        type Answer = (usize, usize);
+
+        fn generate(rng: &mut Rng) -> Vec<u8> {
+            (0..rng.range(1..=30))
+                .map(|i| match i % 2 {
+                    0 => rng.range(1..=9) as u8,
+                    _ => rng.range(0..=9) as u8,
+                })
+                .collect()
+        }
+
+        fn shrink(digits: &Vec<u8>) -> Vec<Vec<u8>> {
+            let mut smaller = Vec::new();
+            if digits.len() > 1 {
+                smaller.push(digits[..digits.len() - 1].to_vec());
+            }
+            for i in (0..digits.len().saturating_sub(2)).step_by(2) {
+                smaller.push([&digits[..i], &digits[i + 2..]].concat());
+            }
+            for i in 0..digits.len() {
+                if digits[i] > (i % 2 == 0) as u8 {
+                    let mut digits = digits.clone();
+                    digits[i] -= 1;
+                    smaller.push(digits);
+                }
+            }
+            smaller
+        }
+
+        fn reference(digits: &Vec<u8>) -> (usize, usize) {
+            let mut blocks = Vec::new();
+            for (i, d) in digits.iter().enumerate() {
+                let block = (i % 2 == 0).then_some(i / 2);
+                blocks.extend(std::iter::repeat_n(block, *d as usize));
+            }
+
+            let mut moved = blocks.clone();
+            let (mut free, mut last) = (0, moved.len());
+            loop {
+                while free < moved.len() && moved[free].is_some() {
+                    free += 1;
+                }
+                while last > 0 && moved[last - 1].is_none() {
+                    last -= 1;
+                }
+                if free + 1 >= last {
+                    break;
+                }
+                moved.swap(free, last - 1);
+            }
+            let part1 = checksum(&moved);
+
+            let files = digits.len().div_ceil(2);
+            for file in (0..files).rev() {
+                let start = blocks.iter().position(|b| *b == Some(file)).unwrap();
+                let len = blocks[start..]
+                    .iter()
+                    .take_while(|b| **b == Some(file))
+                    .count();
+                let space = (0..start).find(|i| blocks[*i..*i + len].iter().all(Option::is_none));
+                if let Some(space) = space {
+                    for k in 0..len {
+                        blocks.swap(space + k, start + k);
+                    }
+                }
+            }
+            (part1, checksum(&blocks))
+        }
+
+        fn production(digits: &Vec<u8>) -> (usize, usize) {
+            let text = digits.iter().map(|d| d.to_string()).collect::<String>();
+            let disk_map = Day09::parse(&Input::new(&text)).unwrap();
+            (
+                Day09::part1(&disk_map).unwrap(),
+                Day09::part2(&disk_map).unwrap(),
+            )
+        }
+    }
+
+    #[test]
+    fn test_differential() {
+        if let Err(mismatch) = check::<Compaction>(seed(), 500) {
+            panic!("{}", mismatch);
+        }
+    }
+
+    #[test]
+    fn test_disk_map_from_str() {
+        let dm = DiskMap::from_compact_str("2333133121414131402").unwrap();
+        assert_eq!(dm.to_string(), "00...111...2...333.44.5555.6666.777.888899");
+
+        let dm = DiskMap::from_compact_str("12345").unwrap();
+        assert_eq!(dm.to_string(), "0..111....22222");
+
+        let err = DiskMap::from_compact_str("123x5").unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (1, 4, "x"));
+    }
+
+    #[test]
+    fn test_disk_map_checksum() {
+        let mut dm = DiskMap::default();
+        dm.entries.push(DiskEntry::Block { file_id: 0, len: 2 });
+        dm.entries.push(DiskEntry::Block { file_id: 9, len: 2 });
+        dm.entries.push(DiskEntry::Block { file_id: 8, len: 1 });
+        assert_eq!(dm.checksum(), 18 + 27 + 32);
+    }
+
+    #[test]
+    fn test_disk_map_compact() {
+        let dm = DiskMap::from_compact_str("12345").unwrap();
+        assert_eq!(dm.compacted().to_string(), "022111222");
+
+        let dm = DiskMap::from_compact_str("2333133121414131402").unwrap();
+        assert_eq!(dm.compacted().to_string(), "0099811188827773336446555566");
+    }
+}
@@ -0,0 +1,156 @@
+use crate::grid::Grid;
+use crate::input::Input;
+use crate::rng::Rng;
+use crate::search::Search;
+use crate::*;
+use anyhow::*;
+use std::result::Result::Ok;
+
+pub fn read_topography(input: &Input) -> Result<Grid<u8>> {
+    let topography = Grid::parse(
+        input,
+        |c| match c {
+            '0'..='9' => Some(c as u8 - b'0'),
+            // Impassable tiles of the examples
+            '.' => Some(c as u8),
+            _ => None,
+        },
+        "height or '.'",
+    )?;
+    Ok(topography)
+}
+
+/// Sums the scores `f` gives to the trailheads, the positions of height 0.
+pub fn score_trails<F: FnMut(&Grid<u8>, Position) -> usize>(map: &Grid<u8>, mut f: F) -> usize {
+    map.find_all(&0).map(|pos| f(map, pos)).sum()
+}
+
+/// Searches the hiking trails from a trailhead, which climb by one at each step.
+fn hike(map: &Grid<u8>, start: Position) -> Search<Position> {
+    map.bfs([start], |height, next| *next == height + 1)
+}
+
+/// Number of tops of height 9 reachable from the trailhead.
+pub fn score_trail(map: &Grid<u8>, start: Position) -> usize {
+    hike(map, start)
+        .reached()
+        .filter(|pos| map[**pos] == 9)
+        .count()
+}
+
+/// Number of distinct trails from the trailhead to a top. Every trail to a top takes 9 steps,
+/// so they are all shortest paths.
+pub fn score_trail_v2(map: &Grid<u8>, start: Position) -> usize {
+    let trails = hike(map, start);
+    map.find_all(&9).map(|top| trails.path_count(&top)).sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        read_topography(input)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        Ok(score_trails(map, score_trail))
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        Ok(score_trails(map, score_trail_v2))
+    }
+}
+
+impl Day10 {
+    /// Generates a square map of `size` tiles per side of random heights, with hiking trails
+    /// from 0 to 9 painted over them, one per 20 tiles, each on tiles not painted yet.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut heights = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| rng.range(0..=9) as u8)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let mut painted = vec![vec![false; size]; size];
+        for _ in 0..size * size / 20 {
+            let mut pos = Some((rng.range(0..=size - 1), rng.range(0..=size - 1)));
+            for height in 0..=9 {
+                let Some((i, j)) = pos.filter(|(i, j)| !painted[*i][*j]) else {
+                    break;
+                };
+                heights[i][j] = height;
+                painted[i][j] = true;
+                let next = [(0, 1), (1, 0), (0, -1), (-1, 0)]
+                    .iter()
+                    .filter_map(|(di, dj)| {
+                        Some((i.checked_add_signed(*di)?, j.checked_add_signed(*dj)?))
+                    })
+                    .filter(|(i, j)| *i < size && *j < size && !painted[*i][*j])
+                    .collect::<Vec<_>>();
+                pos = (!next.is_empty()).then(|| *rng.choose(&next));
+            }
+        }
+        heights
+            .iter()
+            .map(|row| row.iter().map(|h| char::from(b'0' + h)).collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_topography, score_trail, score_trail_v2};
+    use crate::input::Input;
+
+    #[test]
+    fn test_score_trail() {
+        let map = read_topography(&Input::new(
+            r#"0123
+1234
+8765
+9876"#,
+        ))
+        .unwrap();
+
+        assert_eq!(map.area(), ((0, 0), (3, 3)));
+        assert_eq!(score_trail(&map, (0, 0)), 1);
+
+        let map = read_topography(&Input::new(
+            r#"89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732"#,
+        ))
+        .unwrap();
+
+        assert_eq!(map.area(), ((0, 0), (7, 7)));
+        assert_eq!(score_trail(&map, (0, 2)), 5);
+    }
+
+    #[test]
+    fn test_score_trail_v2() {
+        let map = read_topography(&Input::new(
+            r#"012345
+123456
+234567
+345678
+4.6789
+56789."#,
+        ))
+        .unwrap();
+
+        assert_eq!(map.area(), ((0, 0), (5, 5)));
+        assert_eq!(score_trail_v2(&map, (0, 0)), 227);
+    }
+}
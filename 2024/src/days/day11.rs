@@ -0,0 +1,135 @@
+use crate::input::Input;
+use crate::parser::parse_list;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::result::Result::Ok;
+
+fn count_digits(mut n: usize) -> usize {
+    let mut count = 0;
+    while n > 0 {
+        n /= 10;
+        count += 1;
+    }
+    count
+}
+
+fn split_in_half(mut n: usize, n_digits: usize) -> (usize, usize) {
+    let mut m = 0;
+
+    for i in 0..n_digits / 2 {
+        let r = n % 10;
+        m += r * 10_usize.pow(i as u32);
+        n /= 10;
+    }
+
+    (n, m)
+}
+
+pub fn blink(stones: impl Iterator<Item = usize>, blinks: usize) -> u128 {
+    let mut calculator = Calculator::new();
+    stones
+        .into_iter()
+        .map(|stone| calculator.calculate(stone, blinks))
+        .sum()
+}
+
+/// Counts the stones a stone turns into, memoized by stone and number of blinks.
+#[derive(Default)]
+pub struct Calculator {
+    cache: HashMap<(usize, usize), u128>,
+}
+
+impl Calculator {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+
+    pub fn calculate(&mut self, stone: usize, blinks: usize) -> u128 {
+        if blinks == 0 {
+            return 1;
+        }
+        let key = (stone, blinks);
+        if let Some(count) = self.cache.get(&key) {
+            return *count;
+        }
+
+        let count = if stone == 0 {
+            self.calculate(1, blinks - 1)
+        } else {
+            let n_digits = count_digits(stone);
+            if n_digits.is_multiple_of(2) {
+                let (n1, n2) = split_in_half(stone, n_digits);
+                self.calculate(n1, blinks - 1) + self.calculate(n2, blinks - 1)
+            } else {
+                self.calculate(stone * 2024, blinks - 1)
+            }
+        };
+
+        self.cache.insert(key, count);
+        count
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<usize>;
+    type Part1 = u128;
+    type Part2 = u128;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        let line = input.lines().next().context("empty input")?;
+        Ok(parse_list(line, ' ')?)
+    }
+
+    fn part1(stones: &Self::Input) -> Result<u128> {
+        Ok(blink(stones.iter().copied(), 25))
+    }
+
+    fn part2(stones: &Self::Input) -> Result<u128> {
+        Ok(blink(stones.iter().copied(), 75))
+    }
+}
+
+impl Day11 {
+    /// Generates `size` stones engraved with numbers below a million.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let stones = (0..size).map(|_| rng.range(0..=999_999)).join(" ");
+        stones + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{blink, count_digits, split_in_half};
+
+    #[test]
+    fn test_count_digits() {
+        assert_eq!(count_digits(10), 2);
+        assert_eq!(count_digits(999), 3);
+        assert_eq!(count_digits(2097446912), 10);
+    }
+
+    #[test]
+    fn test_split_in_half() {
+        assert_eq!(split_in_half(99, 2), (9, 9));
+        assert_eq!(split_in_half(2097446912, 10), (20974, 46912));
+        assert_eq!(split_in_half(253000, 6), (253, 000));
+    }
+
+    #[test]
+    fn test_blink() {
+        let initial: Vec<usize> = vec![125, 17];
+
+        assert_eq!(blink(initial.clone().into_iter(), 1), 3);
+        assert_eq!(blink(initial.clone().into_iter(), 2), 4);
+        assert_eq!(blink(initial.clone().into_iter(), 3), 5);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::components::Connectivity;
+use crate::grid::Grid;
+use crate::input::Input;
+use crate::rng::Rng;
+use crate::Solution;
+use anyhow::*;
+use std::result::Result::Ok;
+
+pub fn read_garden(input: &Input) -> Result<Grid<char>> {
+    let garden = Grid::parse(input, |c| c.is_ascii_graphic().then_some(c), "plant")?;
+    Ok(garden)
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct Region {
+    plot: char,
+    area: usize,
+    perimeter: usize,
+    sides: usize,
+}
+
+impl Region {
+    pub fn price(&self) -> usize {
+        self.area * self.perimeter
+    }
+
+    pub fn discounted_price(&self) -> usize {
+        self.area * self.sides
+    }
+}
+
+/// Regions of plots of the same plant, in the order of their first plot row by row.
+pub fn collect_regions(garden: &Grid<char>) -> Vec<Region> {
+    garden
+        .components(Connectivity::Four, |a, b| a == b)
+        .components
+        .iter()
+        .map(|component| Region {
+            plot: garden[component.start],
+            area: component.area,
+            perimeter: component.perimeter,
+            sides: component.sides,
+        })
+        .collect()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        read_garden(input)
+    }
+
+    fn part1(garden: &Self::Input) -> Result<usize> {
+        let answer = collect_regions(garden)
+            .iter()
+            .map(Region::price)
+            .sum::<usize>();
+        Ok(answer)
+    }
+
+    fn part2(garden: &Self::Input) -> Result<usize> {
+        let answer = collect_regions(garden)
+            .iter()
+            .map(Region::discounted_price)
+            .sum::<usize>();
+        Ok(answer)
+    }
+}
+
+impl Day12 {
+    /// Generates a square garden of `size` plots per side, most plots growing the plant of the
+    /// plot above or to the left so that regions form.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut plants = vec![vec!['A'; size]; size];
+        for i in 0..size {
+            for j in 0..size {
+                plants[i][j] = match rng.range(0..=9) {
+                    0..=3 if i > 0 => plants[i - 1][j],
+                    4..=7 if j > 0 => plants[i][j - 1],
+                    _ => char::from(b'A' + rng.range(0..=25) as u8),
+                };
+            }
+        }
+        plants
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect_regions, read_garden, Region};
+    use crate::input::Input;
+
+    #[test]
+    fn test_collect_regions_case1() {
+        let garden = read_garden(&Input::new(
+            r#"AAAA
+BBCD
+BBCC
+EEEC"#,
+        ))
+        .unwrap();
+        let regions = collect_regions(&garden);
+        assert_eq!(
+            regions,
+            vec![
+                Region {
+                    plot: 'A',
+                    area: 4,
+                    perimeter: 10,
+                    sides: 4,
+                },
+                Region {
+                    plot: 'B',
+                    area: 4,
+                    perimeter: 8,
+                    sides: 4,
+                },
+                Region {
+                    plot: 'C',
+                    area: 4,
+                    perimeter: 10,
+                    sides: 8,
+                },
+                Region {
+                    plot: 'D',
+                    area: 1,
+                    perimeter: 4,
+                    sides: 4,
+                },
+                Region {
+                    plot: 'E',
+                    area: 3,
+                    perimeter: 8,
+                    sides: 4,
+                }
+            ]
+        );
+
+        assert_eq!(regions.iter().map(Region::price).sum::<usize>(), 140,);
+        assert_eq!(
+            regions.iter().map(Region::discounted_price).sum::<usize>(),
+            80,
+        );
+    }
+
+    #[test]
+    fn test_collect_regions_case2() {
+        let garden = read_garden(&Input::new(
+            r#"OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO"#,
+        ))
+        .unwrap();
+
+        let regions = collect_regions(&garden);
+        assert_eq!(
+            regions.iter().map(Region::discounted_price).sum::<usize>(),
+            436,
+        );
+    }
+
+    #[test]
+    fn test_collect_regions_case3() {
+        let garden = read_garden(&Input::new(
+            r#"EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE"#,
+        ))
+        .unwrap();
+
+        let regions = collect_regions(&garden);
+        assert_eq!(
+            regions,
+            vec![
+                Region {
+                    plot: 'E',
+                    area: 17,
+                    perimeter: 36,
+                    sides: 12
+                },
+                Region {
+                    plot: 'X',
+                    area: 4,
+                    perimeter: 10,
+                    sides: 4
+                },
+                Region {
+                    plot: 'X',
+                    area: 4,
+                    perimeter: 10,
+                    sides: 4
+                },
+            ],
+        );
+        assert_eq!(
+            regions.iter().map(Region::discounted_price).sum::<usize>(),
+            236,
+        );
+    }
+
+    #[test]
+    fn test_collect_regions_case4() {
+        let garden = read_garden(&Input::new(
+            r#"AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA"#,
+        ))
+        .unwrap();
+
+        let regions = collect_regions(&garden);
+        assert_eq!(
+            regions.iter().map(Region::discounted_price).sum::<usize>(),
+            368,
+        );
+    }
+
+    #[test]
+    fn test_collect_regions_case5() {
+        let garden = read_garden(&Input::new(
+            r#"OOOOO
+OXOXO
+OXXXO"#,
+        ))
+        .unwrap();
+
+        let regions = collect_regions(&garden);
+        assert_eq!(
+            regions.iter().map(Region::discounted_price).sum::<usize>(),
+            160,
+        );
+    }
+
+    #[test]
+    fn test_collect_regions_case6() {
+        let garden = read_garden(&Input::new(
+            r#".....
+.AAA.
+.A.A.
+.AA..
+.A.A.
+.AAA.
+....."#,
+        ))
+        .unwrap();
+
+        let regions = collect_regions(&garden);
+        assert_eq!(regions.iter().map(Region::price).sum::<usize>(), 1202,);
+        assert_eq!(
+            regions,
+            vec![
+                Region {
+                    plot: '.',
+                    area: 21,
+                    perimeter: 42,
+                    sides: 12,
+                },
+                Region {
+                    plot: 'A',
+                    area: 12,
+                    perimeter: 26,
+                    sides: 16,
+                },
+                Region {
+                    plot: '.',
+                    area: 1,
+                    perimeter: 4,
+                    sides: 4
+                },
+                Region {
+                    plot: '.',
+                    area: 1,
+                    perimeter: 4,
+                    sides: 4
+                },
+            ]
+        );
+        assert_eq!(
+            regions.iter().map(Region::discounted_price).sum::<usize>(),
+            452,
+        );
+    }
+}
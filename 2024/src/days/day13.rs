@@ -0,0 +1,260 @@
+use crate::input::Input;
+use crate::parser::parse_records;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use std::result::Result::Ok;
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct ClawMachine {
+    a: (usize, usize),
+    b: (usize, usize),
+    prize: Position,
+}
+
+from_line!(
+    ClawMachine,
+    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}",
+    |ax: usize, ay: usize, bx: usize, by: usize, px: usize, py: usize| ClawMachine {
+        a: (ax, ay),
+        b: (bx, by),
+        prize: (px, py),
+    }
+);
+
+pub fn read_claw_machines(input: &Input) -> Result<Vec<ClawMachine>> {
+    Ok(parse_records(input.text())?)
+}
+
+pub fn find_winning_combination(machine: &ClawMachine) -> Option<((usize, usize), usize)> {
+    let b_cf = machine.b.0 as f64 / machine.b.1 as f64;
+    let a_cf = machine.a.0 as f64 - machine.a.1 as f64 * b_cf;
+    let x = (machine.prize.0 as f64 - machine.prize.1 as f64 * b_cf) / a_cf;
+    let y = (machine.prize.0 as f64 - machine.a.0 as f64 * x) / machine.b.0 as f64;
+
+    for a in [x.floor() as usize, x.ceil() as usize] {
+        for b in [y.floor() as usize, y.ceil() as usize] {
+            let px = a * machine.a.0 + b * machine.b.0;
+            let py = a * machine.a.1 + b * machine.b.1;
+            if (px, py) != machine.prize {
+                continue;
+            }
+            let cost = a * 3 + b;
+            return Some(((a, b), cost));
+        }
+    }
+    None
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<ClawMachine>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        read_claw_machines(input)
+    }
+
+    fn part1(machines: &Self::Input) -> Result<usize> {
+        let answer = machines
+            .iter()
+            .filter_map(find_winning_combination)
+            .map(|(_, cost)| cost)
+            .sum();
+        Ok(answer)
+    }
+
+    fn part2(machines: &Self::Input) -> Result<usize> {
+        let delta = 10000000000000;
+        let answer = machines
+            .iter()
+            .map(|machine| ClawMachine {
+                prize: (machine.prize.0 + delta, machine.prize.1 + delta),
+                ..*machine
+            })
+            .filter_map(|machine| find_winning_combination(&machine))
+            .map(|(_, cost)| cost)
+            .sum();
+        Ok(answer)
+    }
+}
+
+impl Day13 {
+    /// Generates `size` claw machines with independent buttons, half of them winnable in at
+    /// most 100 presses of each button.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut machines = Vec::new();
+        while machines.len() < size {
+            let a = (rng.range(10..=99), rng.range(10..=99));
+            let b = (rng.range(10..=99), rng.range(10..=99));
+            if a.0 * b.1 == a.1 * b.0 {
+                continue;
+            }
+            let prize = if rng.chance(0.5) {
+                let (na, nb) = (rng.range(0..=100), rng.range(0..=100));
+                (na * a.0 + nb * b.0, na * a.1 + nb * b.1)
+            } else {
+                (rng.range(1000..=20000), rng.range(1000..=20000))
+            };
+            machines.push(format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            ));
+        }
+        machines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::differential::{check, seed, Differential};
+
+    /// Exact solution of the two equations by Cramer's rule, if it is a non-negative integer.
+    fn solve_exactly(machine: &ClawMachine) -> Option<usize> {
+        let [ax, ay, bx, by, px, py] = [
+            machine.a.0,
+            machine.a.1,
+            machine.b.0,
+            machine.b.1,
+            machine.prize.0,
+            machine.prize.1,
+        ]
+        .map(|n| n as i128);
+        let det = ax * by - ay * bx;
+        let (a, b) = (px * by - py * bx, ax * py - ay * px);
+        if det == 0 || a % det != 0 || b % det != 0 || a / det < 0 || b / det < 0 {
+            return None;
+        }
+        Some((a / det * 3 + b / det) as usize)
+    }
+
+    /// Trying every number of presses up to 100, and solving exactly for the far prizes of part
+    /// 2, against the floating point solution.
+    struct Presses;
+
+    impl Differential for Presses {
+        type Case = Vec<ClawMachine>;
+        type Answer = (usize, usize);
+
+        fn generate(rng: &mut Rng) -> Self::Case {
+            let (mut machines, count) = (Vec::new(), rng.range(1..=4));
+            while machines.len() < count {
+                let a = (rng.range(1..=99), rng.range(1..=99));
+                let b = (rng.range(1..=99), rng.range(1..=99));
+                if a.0 * b.1 == a.1 * b.0 {
+                    continue;
+                }
+                let prize = if rng.chance(0.5) {
+                    let (na, nb) = (rng.range(0..=100), rng.range(0..=100));
+                    (na * a.0 + nb * b.0, na * a.1 + nb * b.1)
+                } else {
+                    (rng.range(0..=10000), rng.range(0..=10000))
+                };
+                machines.push(ClawMachine { a, b, prize });
+            }
+            machines
+        }
+
+        fn shrink(machines: &Self::Case) -> Vec<Self::Case> {
+            (0..machines.len())
+                .filter(|_| machines.len() > 1)
+                .map(|i| [&machines[..i], &machines[i + 1..]].concat())
+                .collect()
+        }
+
+        fn reference(machines: &Self::Case) -> (usize, usize) {
+            let part1 = machines
+                .iter()
+                .filter_map(|m| {
+                    (0..=100)
+                        .flat_map(|a| (0..=100).map(move |b| (a, b)))
+                        .filter(|(a, b)| (a * m.a.0 + b * m.b.0, a * m.a.1 + b * m.b.1) == m.prize)
+                        .map(|(a, b)| a * 3 + b)
+                        .min()
+                })
+                .sum();
+            let part2 = machines
+                .iter()
+                .filter_map(|m| {
+                    let delta = 10000000000000;
+                    solve_exactly(&ClawMachine {
+                        prize: (m.prize.0 + delta, m.prize.1 + delta),
+                        ..*m
+                    })
+                })
+                .sum();
+            (part1, part2)
+        }
+
+        fn production(machines: &Self::Case) -> (usize, usize) {
+            let text = machines
+                .iter()
+                .map(|m| {
+                    format!(
+                        "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n\n",
+                        m.a.0, m.a.1, m.b.0, m.b.1, m.prize.0, m.prize.1
+                    )
+                })
+                .collect::<String>();
+            let machines = Day13::parse(&Input::new(&text)).unwrap();
+            (
+                Day13::part1(&machines).unwrap(),
+                Day13::part2(&machines).unwrap(),
+            )
+        }
+    }
+
+    #[test]
+    fn test_differential() {
+        if let Err(mismatch) = check::<Presses>(seed(), 500) {
+            panic!("{}", mismatch);
+        }
+    }
+
+    const TEST: &str = include_str!("../../examples/13/1.txt");
+
+    #[test]
+    fn test_read_claw_machines() {
+        let machines = read_claw_machines(&Input::new(TEST)).unwrap();
+        assert_eq!(
+            machines,
+            vec![
+                ClawMachine {
+                    a: (94, 34),
+                    b: (22, 67),
+                    prize: (8400, 5400)
+                },
+                ClawMachine {
+                    a: (26, 66),
+                    b: (67, 21),
+                    prize: (12748, 12176)
+                },
+                ClawMachine {
+                    a: (17, 86),
+                    b: (84, 37),
+                    prize: (7870, 6450)
+                },
+                ClawMachine {
+                    a: (69, 23),
+                    b: (27, 71),
+                    prize: (18641, 10279)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_winning_combination() {
+        let comb = find_winning_combination(&ClawMachine {
+            a: (94, 34),
+            b: (22, 67),
+            prize: (8400, 5400),
+        });
+        assert_eq!(comb, Some(((80, 40), 280)));
+    }
+}
@@ -0,0 +1,393 @@
+use crate::bounds::Bounds;
+use crate::input::Input;
+use crate::parser::parse_lines;
+use crate::point::{Point, Vector};
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::result::Result::Ok;
+
+use std::{thread, time};
+use termion::{clear, cursor, event::Key, input::TermRead, raw::IntoRawMode};
+use tracing::debug;
+
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct Robot {
+    position: Point,
+    velocity: Vector,
+}
+
+impl Robot {
+    /// Moves the robot, wrapping around the edges of the area, whose size is given as a vector.
+    fn simulate(self, steps: isize, area: Vector) -> Robot {
+        let position = self.position + self.velocity * steps;
+        Robot {
+            position: Point::from_xy(
+                position.x().rem_euclid(area.x()),
+                position.y().rem_euclid(area.y()),
+            ),
+            ..self
+        }
+    }
+
+    fn quadrant(&self, area: Vector) -> Option<u8> {
+        let mx = area.x() / 2;
+        let my = area.y() / 2;
+        let (x, y) = (self.position.x(), self.position.y());
+        if x < mx && y < my {
+            Some(0)
+        } else if x > mx && y < my {
+            Some(1)
+        } else if x < mx && y > my {
+            Some(2)
+        } else if x > mx && y > my {
+            Some(3)
+        } else {
+            None
+        }
+    }
+}
+
+from_line!(
+    Robot,
+    "p={},{} v={},{}",
+    |px: isize, py: isize, vx: isize, vy: isize| Robot {
+        position: Point::from_xy(px, py),
+        velocity: Point::from_xy(vx, vy),
+    }
+);
+
+pub fn read_input(input: &Input) -> Result<(Vec<Robot>, Vector)> {
+    let robots: Vec<Robot> = parse_lines(input.text())?;
+    // The area starts at the origin and ends at the furthest robots
+    let bounds = Bounds::from_points(
+        robots
+            .iter()
+            .map(|robot| robot.position.into())
+            .chain([(0, 0)]),
+    )
+    .expect("origin is included");
+    let area = Point::from_xy(bounds.width() as isize, bounds.height() as isize);
+    Ok((robots, area))
+}
+
+/// Whether some of the positions form the pattern, given as `(dx, dy)` offsets.
+fn find_pattern(positions: impl Iterator<Item = Point>, pattern: &[(isize, isize)]) -> bool {
+    let positions: HashSet<_> = positions.collect();
+    for position in positions.iter().copied() {
+        if pattern
+            .iter()
+            .all(|(dx, dy)| positions.contains(&(position + Point::from_xy(*dx, *dy))))
+        {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[allow(dead_code)]
+fn inspect_manually(robots: Vec<Robot>, area: Vector) -> Result<()> {
+    let mut seconds = 0;
+
+    let stdout = std::io::stdout();
+    let mut stdout = stdout.lock().into_raw_mode()?;
+    let stdin = std::io::stdin();
+    let mut keys = stdin.keys();
+    let mut buffer = String::new();
+
+    loop {
+        write!(
+            stdout,
+            "{}{}",
+            clear::All,         // Clear the screen
+            cursor::Goto(1, 1)  // Move to top-left corner
+        )?;
+
+        let positions = robots
+            .iter()
+            .map(|r| r.simulate(seconds, area).position)
+            .collect::<HashSet<_>>();
+
+        for y in 0..=area.y() {
+            buffer.clear();
+            for x in 0..=area.x() {
+                if positions.contains(&Point::from_xy(x, y)) {
+                    buffer.push('#');
+                } else {
+                    buffer.push('.');
+                }
+            }
+            writeln!(stdout, "\r{}", buffer)?; // Use '\r' to ensure correct alignment
+        }
+        writeln!(
+            stdout,
+            "\n\rSeconds {}. Press Left/Right to navigate, 'q' to quit.",
+            seconds
+        )?;
+        stdout.flush()?;
+
+        if let Some(Ok(key)) = keys.next() {
+            match key {
+                Key::Right => {
+                    seconds += 1;
+                }
+                Key::Left => {
+                    seconds -= 1;
+                }
+                Key::PageDown => {
+                    seconds += 100;
+                }
+                Key::PageUp => {
+                    seconds -= 100;
+                }
+                Key::Char('q') => {
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        // Add a slight delay to avoid overwhelming the terminal
+        thread::sleep(time::Duration::from_millis(10));
+    }
+
+    Ok(())
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = (Vec<Robot>, Vector);
+    type Part1 = u128;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    fn part1((robots, area): &Self::Input) -> Result<u128> {
+        let area = *area;
+        debug!("Read {} robots in area {:?}", robots.len(), area);
+
+        let mut counter: HashMap<_, u64> = HashMap::new();
+        robots
+            .iter()
+            .filter_map(|robot| robot.simulate(100, area).quadrant(area))
+            .for_each(|q| {
+                counter.entry(q).and_modify(|c| *c += 1).or_insert(1);
+            });
+
+        // A million robots put about 250k in every quadrant, whose product overflows 64 bits
+
+        debug!("Robot counts by quadrant: {:?}", counter);
+        let answer = counter
+            .into_values()
+            .map(u128::from)
+            .reduce(|a, b| a * b)
+            .unwrap_or_default();
+        Ok(answer)
+    }
+
+    fn part2((robots, area): &Self::Input) -> Result<usize> {
+        let mut robots = robots.clone();
+        let area = *area;
+        let mut seconds = 0;
+
+        let pattern = vec![
+            (0, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (-2, 2),
+            (-1, 2),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (-3, 3),
+            (-2, 3),
+            (-1, 3),
+            (0, 3),
+            (1, 3),
+            (2, 3),
+            (2, 3),
+        ];
+
+        loop {
+            seconds += 1;
+            robots.iter_mut().for_each(|r| *r = r.simulate(1, area));
+            let found = find_pattern(robots.iter().map(|r| r.position), &pattern);
+            if found {
+                return Ok(seconds);
+            }
+        }
+    }
+}
+
+impl Day14 {
+    /// Generates `size` robots in the 101x103 area, at least 16 of them forming the top of a
+    /// Christmas tree at some second so that part 2 terminates.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let area = Point::from_xy(101, 103);
+        let tree = [
+            (0, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            (-2, 2),
+            (-1, 2),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (-3, 3),
+            (-2, 3),
+            (-1, 3),
+            (0, 3),
+            (1, 3),
+            (2, 3),
+            (3, 3),
+        ];
+        let velocity = |rng: &mut Rng| {
+            Point::from_xy(
+                rng.range(0..=198) as isize - 99,
+                rng.range(0..=198) as isize - 99,
+            )
+        };
+
+        // The corner robot fixes the area, which is read from the furthest positions.
+        let mut robots = vec![Robot {
+            position: area - Point::from_xy(1, 1),
+            velocity: velocity(rng),
+        }];
+        let seconds = rng.range(1..=10000) as isize;
+        let top = Point::from_xy(rng.range(3..=97) as isize, rng.range(0..=99) as isize);
+        for (dx, dy) in tree {
+            let velocity = velocity(rng);
+            let robot = Robot {
+                position: top + Point::from_xy(dx, dy),
+                velocity,
+            };
+            robots.push(robot.simulate(-seconds, area));
+        }
+        while robots.len() < size {
+            let robot = Robot {
+                position: Point::from_xy(
+                    rng.range(0..=area.x() as usize - 1) as isize,
+                    rng.range(0..=area.y() as usize - 1) as isize,
+                ),
+                velocity: velocity(rng),
+            };
+            robots.push(robot);
+        }
+        rng.shuffle(&mut robots);
+
+        robots
+            .iter()
+            .map(|r| {
+                format!(
+                    "p={},{} v={},{}\n",
+                    r.position.x(),
+                    r.position.y(),
+                    r.velocity.x(),
+                    r.velocity.y()
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_pattern, Day14, Robot};
+    use crate::input::Input;
+    use crate::point::Point;
+    use crate::rng::Rng;
+    use crate::Solution;
+
+    #[test]
+    fn test_safety_factor_of_a_million_robots() {
+        let input = Day14::generate(&mut Rng::new(7), 1_000_000);
+        let robots = Day14::parse(&Input::new(&input)).unwrap();
+        // About 250k robots per quadrant, a product beyond 64 bits
+        assert!(Day14::part1(&robots).unwrap() > u128::from(u64::MAX));
+    }
+
+    #[test]
+    fn test_simulate_robot() {
+        let robot = Robot {
+            position: Point::from_xy(2, 4),
+            velocity: Point::from_xy(2, -3),
+        };
+        let area = Point::from_xy(11, 7);
+
+        // p=(2,4), v=(2,-3); area: (11, 7)
+        // 1: p=(4, 1)
+        // 2: p=(6, -2) -> p(6, 5)
+        // ...
+        // 5: p=(12, -11) -> p=(1, 3)
+        assert_eq!(robot.simulate(1, area).position, Point::from_xy(4, 1));
+        assert_eq!(robot.simulate(2, area).position, Point::from_xy(6, 5));
+        assert_eq!(robot.simulate(5, area).position, Point::from_xy(1, 3));
+    }
+
+    #[test]
+    fn test_quadrant() {
+        let velocity = Point::ZERO;
+        let area = Point::from_xy(11, 7);
+
+        assert_eq!(
+            Robot {
+                position: Point::from_xy(0, 2),
+                velocity
+            }
+            .quadrant(area),
+            Some(0)
+        );
+        assert_eq!(
+            Robot {
+                position: Point::from_xy(6, 0),
+                velocity
+            }
+            .quadrant(area),
+            Some(1)
+        );
+        assert_eq!(
+            Robot {
+                position: Point::from_xy(3, 5),
+                velocity
+            }
+            .quadrant(area),
+            Some(2)
+        );
+        assert_eq!(
+            Robot {
+                position: Point::from_xy(6, 6),
+                velocity
+            }
+            .quadrant(area),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let pattern = vec![
+            (0, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+            // (-2, 2), (-1, 2), (0, 2), (1, 2), (2, 2),
+        ];
+        let found = find_pattern(
+            [(45, 23), (44, 24), (45, 24), (46, 24)]
+                .into_iter()
+                .map(|(x, y)| Point::from_xy(x, y)),
+            &pattern,
+        );
+        assert!(found);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::grid::Grid;
+use crate::input::Input;
+use crate::parser::ParseError;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::result::Result::Ok;
+use tracing::{debug, trace};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Tile {
+    Wall,
+    Box,
+    WBox1,
+    WBox2,
+    Robot,
+    Space,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            '#' => Some(Tile::Wall),
+            'O' => Some(Tile::Box),
+            '@' => Some(Tile::Robot),
+            '[' => Some(Tile::WBox1),
+            ']' => Some(Tile::WBox2),
+            '.' => Some(Tile::Space),
+            _ => None,
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match self {
+            Tile::Wall => '#',
+            Tile::Box => 'O',
+            Tile::WBox1 => '[',
+            Tile::WBox2 => ']',
+            Tile::Robot => '@',
+            Tile::Space => '.',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+fn widen(map: &Grid<Tile>) -> Grid<Tile> {
+    /*
+    If the tile is #, the new map contains ## instead.
+    If the tile is O, the new map contains [] instead.
+    If the tile is ., the new map contains .. instead.
+    If the tile is @, the new map contains @. instead.
+    */
+
+    let rows = map
+        .rows()
+        .map(|row| {
+            row.iter()
+                .flat_map(|tile| match tile {
+                    Tile::Box => [Tile::WBox1, Tile::WBox2],
+                    Tile::Robot => [Tile::Robot, Tile::Space],
+                    Tile::WBox1 | Tile::WBox2 => {
+                        panic!("Trying to widen a wall tile");
+                    }
+                    other => [*other, *other],
+                })
+                .collect()
+        })
+        .collect();
+    Grid::from_rows(rows).expect("rows are as wide")
+}
+
+fn find_robot(map: &Grid<Tile>) -> Option<AbsolutePosition> {
+    map.find(&Tile::Robot)
+        .map(|(i, j)| (i as isize, j as isize))
+}
+
+fn gps_sum(map: &Grid<Tile>, tile: Tile) -> usize {
+    map.find_all(&tile).map(|(x, y)| x * 100 + y).sum()
+}
+
+pub fn read_input(input: &Input) -> Result<(Grid<Tile>, Vec<Cardinal>)> {
+    let [map_section, moves_section] = &input.sections()[..] else {
+        bail!("expected a map and moves separated by a blank line");
+    };
+
+    let map = Grid::parse(map_section, Tile::from_char, "tile")?;
+    let mut directions = Vec::new();
+    for (i, line) in moves_section.numbered_lines() {
+        for (offset, c) in line.char_indices() {
+            let dir = Cardinal::from_symbol(c)
+                .ok_or_else(|| ParseError::char_at(line, offset, "move").offset(i))?;
+            directions.push(dir);
+        }
+    }
+
+    ensure!(map.find(&Tile::Robot).is_some(), "no robot found");
+    Ok((map, directions))
+}
+
+#[derive(Default)]
+struct Lane {
+    tiles: Vec<AbsolutePosition>,
+}
+
+pub fn apply_moves(mut map: Grid<Tile>, directions: &[Cardinal]) -> Grid<Tile> {
+    let mut robot = find_robot(&map).expect("robot exists");
+    let area = map.absolute_area();
+
+    for dir in directions.iter().copied() {
+        // Leap in direction
+        // Case 0: Space, stop and move all tiles to this pos. @.
+        // Case 1: Wall, stop: @#
+        // Case 2: Box, add to moved: @O
+
+        let mut lanes: BTreeMap<i8, Lane> = BTreeMap::new();
+        lanes.entry(0).or_default().tiles.push(robot);
+
+        let mut step_pos = robot;
+        'step: while let Some(next_pos) = aleap_in_bounds(step_pos, dir, 1, &area) {
+            let mut n_spaces = 0;
+            for lane in lanes.keys().cloned().collect_vec() {
+                let lane_pos = aleap(next_pos, dir.turn_right(), lane as isize);
+                let lane_tile = map.get_abs(lane_pos).copied();
+
+                match lane_tile {
+                    Some(Tile::Box) => {
+                        lanes.entry(lane).or_default().tiles.push(lane_pos);
+                    }
+                    Some(Tile::WBox1) | Some(Tile::WBox2) => {
+                        lanes.entry(lane).or_default().tiles.push(lane_pos);
+
+                        let new_lane = match (lane_tile, dir) {
+                            (Some(Tile::WBox1), Cardinal::N) => Some(lane + 1),
+                            (Some(Tile::WBox2), Cardinal::N) => Some(lane - 1),
+                            (Some(Tile::WBox1), Cardinal::S) => Some(lane - 1),
+                            (Some(Tile::WBox2), Cardinal::S) => Some(lane + 1),
+                            _ => None,
+                        };
+                        if let Some(new_lane) = new_lane {
+                            let new_lane_pos = aleap(next_pos, dir.turn_right(), new_lane as isize);
+                            lanes.entry(new_lane).or_default().tiles.push(new_lane_pos);
+                        }
+                    }
+                    Some(Tile::Space) => {
+                        n_spaces += 1;
+                        lanes.entry(lane).or_default().tiles.push(lane_pos);
+                    }
+                    _ => {
+                        lanes.clear();
+                        break 'step;
+                    }
+                }
+            }
+
+            if n_spaces == lanes.len() {
+                break;
+            }
+            step_pos = next_pos;
+        }
+        if lanes.is_empty() {
+            continue;
+        }
+
+        for lane in lanes.values() {
+            for i in (1..lane.tiles.len()).rev() {
+                let (src, dst) = (lane.tiles[i], lane.tiles[i - 1]);
+                map.swap(
+                    (src.0 as usize, src.1 as usize),
+                    (dst.0 as usize, dst.1 as usize),
+                );
+            }
+        }
+        robot = aleap(robot, dir, 1);
+    }
+
+    map
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = (Grid<Tile>, Vec<Cardinal>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        read_input(input)
+    }
+
+    fn part1((map, directions): &Self::Input) -> Result<usize> {
+        let robot_pos = find_robot(map).expect("robot found");
+        debug!("Map: {:?}, robot: {:?}", map.area(), robot_pos);
+
+        let map = apply_moves(map.clone(), directions);
+        Ok(gps_sum(&map, Tile::Box))
+    }
+
+    fn part2((map, directions): &Self::Input) -> Result<usize> {
+        let mut map = widen(map);
+        debug!("Initial:\n{}", map);
+        for dir in directions.iter().copied() {
+            map = apply_moves(map, &[dir]);
+            trace!("Move: {:?}\n{}", dir, map);
+        }
+        Ok(gps_sum(&map, Tile::WBox1))
+    }
+}
+
+impl Day15 {
+    /// Generates a square walled warehouse of `size` tiles per side, with one box in 5 tiles
+    /// and one wall in 10, and `400 * size` moves of the robot.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(3);
+        let mut rows = (0..size)
+            .map(|i| {
+                (0..size)
+                    .map(|j| match rng.range(0..=9) {
+                        _ if i == 0 || j == 0 || i == size - 1 || j == size - 1 => '#',
+                        0 => '#',
+                        1 | 2 => 'O',
+                        _ => '.',
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        rows[rng.range(1..=size - 2)][rng.range(1..=size - 2)] = '@';
+
+        let mut text = rows
+            .iter()
+            .map(|row| row.iter().collect::<String>() + "\n")
+            .collect::<String>();
+        text.push('\n');
+        let moves = (0..400 * size)
+            .map(|_| *rng.choose(&['^', 'v', '<', '>']))
+            .collect::<Vec<_>>();
+        for line in moves.chunks(1000) {
+            text.extend(line);
+            text.push('\n');
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Map of `size` surrounded by walls, with the given tiles inside.
+    fn walled_map(size: Position, tiles: &[(Tile, Position)]) -> Grid<Tile> {
+        let mut map = Grid::new(size.0, size.1, Tile::Wall);
+        for i in 1..size.0 - 1 {
+            for j in 1..size.1 - 1 {
+                map[(i, j)] = Tile::Space;
+            }
+        }
+        for (tile, pos) in tiles {
+            map[*pos] = *tile;
+        }
+        map
+    }
+
+    #[test]
+    fn test_apply_moves() {
+        let map = walled_map(
+            (7, 7),
+            &[
+                (Tile::Wall, (1, 4)),
+                (Tile::Box, (3, 3)),
+                (Tile::Box, (3, 4)),
+                (Tile::Box, (4, 3)),
+                (Tile::Robot, (3, 5)),
+            ],
+        );
+
+        let wide_map = widen(&map);
+        assert_eq!(
+            wide_map.find_all(&Tile::WBox1).collect_vec(),
+            vec![(3, 6), (3, 8), (4, 6)]
+        );
+
+        let wide_map = apply_moves(
+            wide_map,
+            &[
+                Cardinal::W,
+                Cardinal::S,
+                Cardinal::S,
+                Cardinal::W,
+                Cardinal::W,
+            ],
+        );
+        assert_eq!(
+            wide_map.find_all(&Tile::WBox1).collect_vec(),
+            vec![(3, 5), (3, 7), (4, 6)]
+        );
+        assert_eq!(find_robot(&wide_map), Some((5, 7)));
+
+        let move_2 = vec![Cardinal::N, Cardinal::N];
+        let wide_map = apply_moves(wide_map, &move_2);
+        assert_eq!(
+            wide_map.find_all(&Tile::WBox1).collect_vec(),
+            vec![(2, 5), (2, 7), (3, 6)]
+        );
+        assert_eq!(find_robot(&wide_map), Some((4, 7)));
+    }
+}
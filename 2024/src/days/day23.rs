@@ -0,0 +1,192 @@
+use crate::input::Input;
+use crate::parser::Pattern;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::result::Result::Ok;
+
+pub fn bron_kerbosch(
+    graph: &HashMap<usize, HashSet<usize>>,
+    on_clique: &mut impl FnMut(&HashSet<usize>),
+    r: &mut HashSet<usize>,
+    p: &mut HashSet<usize>,
+    x: &mut HashSet<usize>,
+) {
+    if p.is_empty() && x.is_empty() {
+        on_clique(r);
+        return;
+    }
+
+    while !p.is_empty() {
+        let Some(v) = p.iter().next().cloned() else {
+            continue;
+        };
+
+        let mut new_r = r.clone();
+        new_r.insert(v);
+
+        let mut new_p = HashSet::new();
+        for pv in p.iter() {
+            if graph.get(&v).is_some_and(|vs| vs.contains(pv)) {
+                new_p.insert(*pv);
+            }
+        }
+
+        let mut new_x = HashSet::new();
+        for xv in x.iter() {
+            if graph.get(&v).is_some_and(|vs| vs.contains(xv)) {
+                new_x.insert(*xv);
+            }
+        }
+
+        bron_kerbosch(graph, on_clique, &mut new_r, &mut new_p, &mut new_x);
+
+        p.remove(&v);
+        x.insert(v);
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Vec<(String, String)>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        Ok(Pattern::new("{}-{}").parse_lines(input.text())?)
+    }
+
+    fn part1(connections: &Self::Input) -> Result<usize> {
+        let mut graph: HashMap<&String, HashSet<&String>> = HashMap::new();
+        connections.iter().for_each(|(from, to)| {
+            graph.entry(from).or_default().insert(to);
+            graph.entry(to).or_default().insert(from);
+        });
+
+        let mut answer = 0;
+        for (c1, c1_bros) in graph.iter() {
+            for c2 in c1_bros {
+                if c1 >= c2 {
+                    continue;
+                }
+                let Some(c2_bros) = graph.get(c2) else {
+                    continue;
+                };
+                for c3 in c2_bros {
+                    if c2 >= c3 {
+                        continue;
+                    }
+                    let Some(c3_bros) = graph.get(c3) else {
+                        continue;
+                    };
+                    if c3_bros.contains(c1) {
+                        let has_t =
+                            c1.starts_with('t') || c2.starts_with('t') || c3.starts_with('t');
+                        if has_t {
+                            answer += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(answer)
+    }
+
+    fn part2(connections: &Self::Input) -> Result<String> {
+        let mut names: Vec<&String> = Vec::new();
+        let mut name_registry = HashMap::new();
+        let mut graph: HashMap<usize, HashSet<usize>> = HashMap::new();
+
+        connections.iter().for_each(|(from, to)| {
+            let from = if let Some(from) = name_registry.get(from) {
+                *from
+            } else {
+                let idx = names.len();
+                name_registry.insert(from, idx);
+                names.push(from);
+                idx
+            };
+
+            let to = if let Some(to) = name_registry.get(to) {
+                *to
+            } else {
+                let idx = names.len();
+                name_registry.insert(to, idx);
+                names.push(to);
+                idx
+            };
+
+            graph.entry(from).or_default().insert(to);
+            graph.entry(to).or_default().insert(from);
+        });
+
+        let mut max_clique_size = 0;
+        let mut max_clique_name = String::new();
+
+        bron_kerbosch(
+            &graph,
+            &mut |clique: &HashSet<usize>| {
+                if clique.len() > max_clique_size {
+                    max_clique_size = clique.len();
+                    max_clique_name = clique
+                        .iter()
+                        .filter_map(|idx| names.get(*idx))
+                        .sorted()
+                        .join(",");
+                }
+            },
+            &mut HashSet::new(),
+            &mut graph.keys().cloned().collect(),
+            &mut HashSet::new(),
+        );
+        Ok(max_clique_name)
+    }
+}
+
+impl Day23 {
+    /// Generates a network of `size` computers with 13 connections each on average and a
+    /// planted LAN party of 13. There are at least 27 computers so that the connections fit
+    /// with about half of the pairs left unconnected, keeping the planted party the largest.
+    /// Names are two letters long, or longer for more than 676 computers.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(27);
+        let width = (2..).find(|w| 26usize.pow(*w) >= size).unwrap_or(2);
+        let name = |mut n: usize| {
+            let mut name = vec![b'a'; width as usize];
+            for c in name.iter_mut().rev() {
+                *c += (n % 26) as u8;
+                n /= 26;
+            }
+            String::from_utf8(name).expect("ascii name")
+        };
+
+        let mut computers = (0..size).collect::<Vec<_>>();
+        rng.shuffle(&mut computers);
+        let mut connections = HashSet::new();
+        for (i, a) in computers[..13].iter().enumerate() {
+            for b in &computers[i + 1..13] {
+                connections.insert((*a.min(b), *a.max(b)));
+            }
+        }
+        while connections.len() < size * 13 / 2 {
+            let (a, b) = (rng.range(0..=size - 1), rng.range(0..=size - 1));
+            if a != b {
+                connections.insert((a.min(b), a.max(b)));
+            }
+        }
+
+        let mut connections = connections.into_iter().collect::<Vec<_>>();
+        connections.sort();
+        rng.shuffle(&mut connections);
+        connections
+            .into_iter()
+            .map(|(a, b)| format!("{}-{}\n", name(a), name(b)))
+            .collect()
+    }
+}
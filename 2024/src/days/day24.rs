@@ -0,0 +1,211 @@
+use crate::input::Input;
+use crate::parser::{ParseError, Pattern};
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::result::Result::Ok;
+
+#[derive(Debug)]
+pub enum Gate {
+    And {
+        in1: String,
+        in2: String,
+        out: String,
+    },
+    Or {
+        in1: String,
+        in2: String,
+        out: String,
+    },
+    Xor {
+        in1: String,
+        in2: String,
+        out: String,
+    },
+}
+
+impl Gate {
+    fn output(&self) -> &String {
+        match self {
+            Gate::And { out, .. } => out,
+            Gate::Or { out, .. } => out,
+            Gate::Xor { out, .. } => out,
+        }
+    }
+
+    fn in1(&self) -> &String {
+        match self {
+            Gate::And { in1, .. } => in1,
+            Gate::Or { in1, .. } => in1,
+            Gate::Xor { in1, .. } => in1,
+        }
+    }
+
+    fn in2(&self) -> &String {
+        match self {
+            Gate::And { in2, .. } => in2,
+            Gate::Or { in2, .. } => in2,
+            Gate::Xor { in2, .. } => in2,
+        }
+    }
+}
+
+pub fn parse_input(input: &Input) -> Result<(HashMap<String, u8>, Vec<Gate>)> {
+    let [wires_section, gates_section] = &input.sections()[..] else {
+        bail!("expected wires and gates separated by a blank line");
+    };
+    let wire = Pattern::new("{}: {}");
+    let gate = Pattern::new("{} {} {} -> {}");
+
+    let mut wires = HashMap::new();
+    for (i, line) in wires_section.numbered_lines() {
+        let (name, value) = wire
+            .parse::<(String, u8)>(line)
+            .map_err(|err| err.offset(i))?;
+        wires.insert(name, value);
+    }
+
+    let mut gates = Vec::new();
+    for (i, line) in gates_section.numbered_lines() {
+        let (in1, gate_type, in2, out) = gate
+            .parse::<(String, String, String, String)>(line)
+            .map_err(|err| err.offset(i))?;
+        let gate = match gate_type.as_str() {
+            "AND" => Gate::And { in1, in2, out },
+            "OR" => Gate::Or { in1, in2, out },
+            "XOR" => Gate::Xor { in1, in2, out },
+            _ => {
+                let offset = line[in1.len()..].find(&gate_type).unwrap_or_default() + in1.len();
+                let expected = "gate type AND, OR or XOR";
+                return Err(ParseError::at(line, offset, expected).offset(i).into());
+            }
+        };
+        gates.push(gate);
+    }
+
+    Ok((wires, gates))
+}
+
+pub fn evaluate_gates(mut wires: HashMap<String, u8>, gates: &[Gate]) -> HashMap<String, u8> {
+    let mut undecided_gates: HashSet<_> = (0..gates.len()).collect();
+    let mut decided_gates = Vec::new();
+
+    while !undecided_gates.is_empty() {
+        decided_gates.clear();
+        decided_gates.extend(undecided_gates.iter().map(|i| (i, &gates[*i])).filter_map(
+            |(i, gate)| {
+                let in1 = *wires.get(gate.in1())?;
+                let in2 = *wires.get(gate.in2())?;
+
+                let output = match gate {
+                    Gate::And { .. } => in1 & in2,
+                    Gate::Or { .. } => in1 | in2,
+                    Gate::Xor { .. } => in1 ^ in2,
+                };
+                wires.insert(gate.output().clone(), output);
+                Some(*i)
+            },
+        ));
+
+        if decided_gates.is_empty() {
+            break;
+        } else {
+            for gate in decided_gates.iter() {
+                undecided_gates.remove(gate);
+            }
+        }
+    }
+
+    wires
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = (HashMap<String, u8>, Vec<Gate>);
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((wires, gates): &Self::Input) -> Result<usize> {
+        let answer = evaluate_gates(wires.clone(), gates)
+            .into_iter()
+            .filter(|(name, _)| name.starts_with("z"))
+            .collect::<BTreeMap<_, _>>()
+            .values()
+            .copied()
+            .enumerate()
+            .map(|(i, v)| usize::from(v) * 2_usize.pow(i as u32))
+            .sum();
+        Ok(answer)
+    }
+}
+
+impl Day24 {
+    /// Generates a correct ripple-carry adder of `size` bit numbers, with the gates shuffled.
+    /// The size is capped at 63 bits, as the output must fit in a `usize`.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        let bits = size.clamp(1, 63);
+        let mut used = HashSet::new();
+        let mut wire = |rng: &mut Rng| loop {
+            let name = (0..3)
+                .map(|_| char::from(b'a' + rng.range(0..=22) as u8))
+                .collect::<String>();
+            if used.insert(name.clone()) {
+                return name;
+            }
+        };
+
+        let mut gates = Vec::new();
+        let mut gate = |rng: &mut Rng, in1: &str, op: &str, in2: &str, out: &str| {
+            let (in1, in2) = if rng.chance(0.5) {
+                (in1, in2)
+            } else {
+                (in2, in1)
+            };
+            gates.push(format!("{} {} {} -> {}\n", in1, op, in2, out));
+        };
+        let mut carry = match bits {
+            1 => "z01".to_string(),
+            _ => wire(rng),
+        };
+        gate(rng, "x00", "XOR", "y00", "z00");
+        gate(rng, "x00", "AND", "y00", &carry);
+        for i in 1..bits {
+            let (x, y, z) = (
+                format!("x{:02}", i),
+                format!("y{:02}", i),
+                format!("z{:02}", i),
+            );
+            let [sum, both, propagated, next] = [(); 4].map(|_| wire(rng));
+            gate(rng, &x, "XOR", &y, &sum);
+            gate(rng, &sum, "XOR", &carry, &z);
+            gate(rng, &x, "AND", &y, &both);
+            gate(rng, &sum, "AND", &carry, &propagated);
+            let next = if i == bits - 1 {
+                format!("z{:02}", bits)
+            } else {
+                next
+            };
+            gate(rng, &both, "OR", &propagated, &next);
+            carry = next;
+        }
+        rng.shuffle(&mut gates);
+
+        let mut text = String::new();
+        for prefix in ['x', 'y'] {
+            for i in 0..bits {
+                text.push_str(&format!("{}{:02}: {}\n", prefix, i, rng.range(0..=1)));
+            }
+        }
+        text.push('\n');
+        text.extend(gates);
+        text
+    }
+}
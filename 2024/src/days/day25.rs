@@ -0,0 +1,105 @@
+use crate::input::Input;
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use std::result::Result::Ok;
+
+pub type Heights = Vec<u8>;
+
+pub fn read_schematics(input: &Input) -> Result<(Vec<Heights>, Vec<Heights>)> {
+    let (mut locks, mut keys) = (Vec::new(), Vec::new());
+    for scheme in input.sections() {
+        let grid = scheme.grid_with(
+            |c| match c {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+            "'#' or '.'",
+        )?;
+        let Some((top, rows)) = grid.split_first() else {
+            continue;
+        };
+        let is_lock = top.iter().all(|filled| *filled);
+
+        // The bottom row of a key is filled and does not count
+        let heights = (0..top.len())
+            .map(|j| {
+                let filled = rows.iter().filter(|row| row[j]).count();
+                let height = if is_lock {
+                    filled
+                } else {
+                    filled.saturating_sub(1)
+                };
+                height as u8
+            })
+            .collect();
+        if is_lock {
+            locks.push(heights);
+        } else {
+            keys.push(heights);
+        }
+    }
+
+    Ok((locks, keys))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = (Vec<Heights>, Vec<Heights>);
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn parse(input: &Input) -> Result<Self::Input> {
+        read_schematics(input)
+    }
+
+    fn part1((locks, keys): &Self::Input) -> Result<usize> {
+        let answer = keys
+            .iter()
+            .map(|key| {
+                locks
+                    .iter()
+                    .filter(|lock| key.iter().zip(lock.iter()).all(|(x, y)| x + y <= 5))
+                    .count()
+            })
+            .sum();
+        Ok(answer)
+    }
+}
+
+impl Day25 {
+    /// Generates `size` schematics of five pin columns, locks and keys alike.
+    pub fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let is_lock = rng.chance(0.5);
+                let heights = (0..5).map(|_| rng.range(0..=5)).collect::<Vec<_>>();
+                (0..7)
+                    .map(|row| {
+                        let line = heights
+                            .iter()
+                            .map(|height| {
+                                let filled = if is_lock {
+                                    row <= *height
+                                } else {
+                                    row >= 6 - height
+                                };
+                                if filled {
+                                    '#'
+                                } else {
+                                    '.'
+                                }
+                            })
+                            .collect::<String>();
+                        line + "\n"
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,47 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub use day01::Day01;
+pub use day02::Day02;
+pub use day03::Day03;
+pub use day04::Day04;
+pub use day05::Day05;
+pub use day06::Day06;
+pub use day07::Day07;
+pub use day08::Day08;
+pub use day09::Day09;
+pub use day10::Day10;
+pub use day11::Day11;
+pub use day12::Day12;
+pub use day13::Day13;
+pub use day14::Day14;
+pub use day15::Day15;
+pub use day23::Day23;
+pub use day24::Day24;
+pub use day25::Day25;
+
+use crate::Solver;
+
+/// Prefix of the log targets of the days.
+pub(crate) const TARGET: &str = module_path!();
+
+pub static SOLVERS: &[&dyn Solver] = &[
+    &Day01, &Day02, &Day03, &Day04, &Day05, &Day06, &Day07, &Day08, &Day09, &Day10, &Day11, &Day12,
+    &Day13, &Day14, &Day15, &Day23, &Day24, &Day25,
+];
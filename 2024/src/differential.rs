@@ -0,0 +1,137 @@
+use crate::rng::Rng;
+use std::fmt::{Debug, Display};
+
+/// Environment variable overriding the seed of the differential checks.
+pub const SEED_ENV: &str = "AOC_SEED";
+pub const DEFAULT_SEED: u64 = 2024;
+
+/// A reference solver, usually brute force, and the production solver of a day, which must give
+/// the same answers on every valid input.
+pub trait Differential {
+    /// Generated input, kept structured so that it can be shrunk
+    type Case: Clone + Debug;
+    type Answer: Debug + PartialEq;
+
+    fn generate(rng: &mut Rng) -> Self::Case;
+
+    /// Smaller variants of a case, tried in order while shrinking a mismatch.
+    fn shrink(case: &Self::Case) -> Vec<Self::Case>;
+
+    fn reference(case: &Self::Case) -> Self::Answer;
+
+    fn production(case: &Self::Case) -> Self::Answer;
+}
+
+/// Case on which the solvers disagree, shrunk as far as possible.
+#[derive(Clone, Debug)]
+pub struct Mismatch<C, A> {
+    /// Seed generating the original case
+    pub seed: u64,
+    pub case: C,
+    pub reference: A,
+    pub production: A,
+    /// Number of successful shrinking steps
+    pub shrinks: usize,
+}
+
+impl<C: Debug, A: Debug> Display for Mismatch<C, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "seed {}: reference {:?}, production {:?} after {} shrinks, on {:#?}",
+            self.seed, self.reference, self.production, self.shrinks, self.case
+        )
+    }
+}
+
+/// Seed of the differential checks, taken from `AOC_SEED` if set.
+pub fn seed() -> u64 {
+    std::env::var(SEED_ENV)
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or(DEFAULT_SEED)
+}
+
+/// Compares the solvers on `cases` random cases, the i-th one generated from `seed + i`, and
+/// shrinks the first mismatch by greedily taking the first smaller variant that still fails.
+pub fn check<D: Differential>(seed: u64, cases: u64) -> Result<(), Mismatch<D::Case, D::Answer>> {
+    for i in 0..cases {
+        let seed = seed.wrapping_add(i);
+        let case = D::generate(&mut Rng::new(seed));
+        let (reference, production) = (D::reference(&case), D::production(&case));
+        if reference == production {
+            continue;
+        }
+
+        let mut mismatch = Mismatch {
+            seed,
+            case,
+            reference,
+            production,
+            shrinks: 0,
+        };
+        'shrink: loop {
+            for case in D::shrink(&mismatch.case) {
+                let (reference, production) = (D::reference(&case), D::production(&case));
+                if reference != production {
+                    mismatch = Mismatch {
+                        case,
+                        reference,
+                        production,
+                        shrinks: mismatch.shrinks + 1,
+                        ..mismatch
+                    };
+                    continue 'shrink;
+                }
+            }
+            return Err(mismatch);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sums numbers, the production solver wrongly ignoring those above 50.
+    struct Sum;
+
+    impl Differential for Sum {
+        type Case = Vec<usize>;
+        type Answer = usize;
+
+        fn generate(rng: &mut Rng) -> Vec<usize> {
+            (0..rng.range(0..=10)).map(|_| rng.range(0..=60)).collect()
+        }
+
+        fn shrink(case: &Vec<usize>) -> Vec<Vec<usize>> {
+            let mut smaller = (0..case.len())
+                .map(|i| [&case[..i], &case[i + 1..]].concat())
+                .collect::<Vec<_>>();
+            for i in 0..case.len() {
+                if case[i] > 0 {
+                    let mut case = case.clone();
+                    case[i] -= 1;
+                    smaller.push(case);
+                }
+            }
+            smaller
+        }
+
+        fn reference(case: &Vec<usize>) -> usize {
+            case.iter().sum()
+        }
+
+        fn production(case: &Vec<usize>) -> usize {
+            case.iter().filter(|n| **n <= 50).sum()
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mismatch = check::<Sum>(seed(), 100).unwrap_err();
+        assert_eq!(mismatch.case, vec![51]);
+        assert_eq!((mismatch.reference, mismatch.production), (51, 0));
+    }
+}
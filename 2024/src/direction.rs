@@ -0,0 +1,331 @@
+use crate::point::{Point, Vector};
+use anyhow::*;
+use std::fmt::Display;
+use std::result::Result::Ok;
+use std::slice::Iter;
+use std::str::FromStr;
+
+/// One of the 4 directions along the sides of a tile, north being up.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Cardinal {
+    N,
+    E,
+    S,
+    W,
+}
+
+/// One of the 8 directions towards the sides and the corners of a tile, north being up.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    N,
+    S,
+    W,
+    E,
+    NE,
+    NW,
+    SE,
+    SW,
+}
+
+impl Cardinal {
+    const CLOCKWISE: [Cardinal; 4] = [Cardinal::N, Cardinal::E, Cardinal::S, Cardinal::W];
+
+    /// The 4 directions clockwise from north.
+    pub fn iter() -> Iter<'static, Cardinal> {
+        Self::CLOCKWISE.iter()
+    }
+
+    /// Parses `^>v<`, arrows, `URDL` or `NESW`.
+    pub fn from_symbol(c: char) -> Option<Cardinal> {
+        use Cardinal::*;
+        match c {
+            '^' | '↑' | 'U' | 'N' => Some(N),
+            '>' | '→' | 'R' | 'E' => Some(E),
+            'v' | '↓' | 'D' | 'S' => Some(S),
+            '<' | '←' | 'L' | 'W' => Some(W),
+            _ => None,
+        }
+    }
+
+    /// Symbol of the puzzles, one of `^>v<`.
+    pub fn symbol(self) -> char {
+        ['^', '>', 'v', '<'][self.index()]
+    }
+
+    /// One of `URDL`.
+    pub fn letter(self) -> char {
+        ['U', 'R', 'D', 'L'][self.index()]
+    }
+
+    pub fn name(self) -> &'static str {
+        ["north", "east", "south", "west"][self.index()]
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Rotates by `n` quarter turns, clockwise for positive `n`.
+    pub fn rotate(self, n: isize) -> Cardinal {
+        Self::CLOCKWISE[(self.index() as isize + n).rem_euclid(4) as usize]
+    }
+
+    pub fn opposite(self) -> Cardinal {
+        self.rotate(2)
+    }
+
+    pub fn turn_right(self) -> Cardinal {
+        self.rotate(1)
+    }
+
+    pub fn turn_left(self) -> Cardinal {
+        self.rotate(-1)
+    }
+
+    /// Step of one tile in the direction.
+    pub fn vector(self) -> Vector {
+        Direction::from(self).vector()
+    }
+}
+
+impl From<Cardinal> for Direction {
+    fn from(dir: Cardinal) -> Self {
+        match dir {
+            Cardinal::N => Direction::N,
+            Cardinal::E => Direction::E,
+            Cardinal::S => Direction::S,
+            Cardinal::W => Direction::W,
+        }
+    }
+}
+
+/// Prints the name, e.g. `north`.
+impl Display for Cardinal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Parses a symbol of [`Cardinal::from_symbol`], a name or `up`, `right`, `down` and `left`,
+/// ignoring case for words.
+impl FromStr for Cardinal {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Cardinal::from_symbol(c).ok_or_else(|| anyhow!("invalid direction: {}", s));
+        }
+        match s.to_lowercase().as_str() {
+            "north" | "up" => Ok(Cardinal::N),
+            "east" | "right" => Ok(Cardinal::E),
+            "south" | "down" => Ok(Cardinal::S),
+            "west" | "left" => Ok(Cardinal::W),
+            _ => Err(anyhow!("invalid direction: {}", s)),
+        }
+    }
+}
+
+impl Direction {
+    const CLOCKWISE: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+    const NAMES: [&'static str; 8] = [
+        "north",
+        "north-east",
+        "east",
+        "south-east",
+        "south",
+        "south-west",
+        "west",
+        "north-west",
+    ];
+    const COMPASS: [&'static str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+
+    /// The 8 directions, the cardinal ones first.
+    pub fn iter() -> Iter<'static, Direction> {
+        use Direction::*;
+        static DIRECTIONS: [Direction; 8] = [N, S, W, E, NE, NW, SE, SW];
+        DIRECTIONS.iter()
+    }
+
+    /// Parses the symbols of [`Cardinal::from_symbol`] and the diagonal arrows `↗↘↙↖`.
+    pub fn from_symbol(c: char) -> Option<Direction> {
+        match c {
+            '↗' => Some(Direction::NE),
+            '↘' => Some(Direction::SE),
+            '↙' => Some(Direction::SW),
+            '↖' => Some(Direction::NW),
+            _ => Cardinal::from_symbol(c).map(Direction::from),
+        }
+    }
+
+    /// One of the arrows `↑↗→↘↓↙←↖`.
+    pub fn arrow(self) -> char {
+        ['↑', '↗', '→', '↘', '↓', '↙', '←', '↖'][self.index()]
+    }
+
+    /// Abbreviation of the name, e.g. `NE`.
+    pub fn compass(self) -> &'static str {
+        Self::COMPASS[self.index()]
+    }
+
+    pub fn name(self) -> &'static str {
+        Self::NAMES[self.index()]
+    }
+
+    /// The direction as a cardinal one, `None` for the diagonals.
+    pub fn cardinal(self) -> Option<Cardinal> {
+        match self {
+            Direction::N => Some(Cardinal::N),
+            Direction::E => Some(Cardinal::E),
+            Direction::S => Some(Cardinal::S),
+            Direction::W => Some(Cardinal::W),
+            _ => None,
+        }
+    }
+
+    /// Position in [`Direction::CLOCKWISE`].
+    fn index(self) -> usize {
+        use Direction::*;
+        match self {
+            N => 0,
+            NE => 1,
+            E => 2,
+            SE => 3,
+            S => 4,
+            SW => 5,
+            W => 6,
+            NW => 7,
+        }
+    }
+
+    /// Rotates by `n` eighths of a turn, clockwise for positive `n`.
+    pub fn rotate(self, n: isize) -> Direction {
+        Self::CLOCKWISE[(self.index() as isize + n).rem_euclid(8) as usize]
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    /// Turns right by 45 degrees.
+    pub fn turn_45_deg(self) -> Direction {
+        self.rotate(1)
+    }
+
+    /// Turns left by 45 degrees, the inverse of [`Direction::turn_45_deg`].
+    pub fn turn_left_45_deg(self) -> Direction {
+        self.rotate(-1)
+    }
+
+    /// Step of one tile in the direction.
+    pub fn vector(self) -> Vector {
+        use Direction::*;
+        match self {
+            N => Point::new(-1, 0),
+            S => Point::new(1, 0),
+            W => Point::new(0, -1),
+            E => Point::new(0, 1),
+            NE => Point::new(-1, 1),
+            NW => Point::new(-1, -1),
+            SE => Point::new(1, 1),
+            SW => Point::new(1, -1),
+        }
+    }
+}
+
+/// Prints the name, e.g. `north-east`.
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Parses a symbol of [`Direction::from_symbol`], an abbreviation such as `NE`, or a name with
+/// or without a hyphen or space, ignoring case for words.
+impl FromStr for Direction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Direction::from_symbol(c).ok_or_else(|| anyhow!("invalid direction: {}", s));
+        }
+        if let Ok(dir) = s.parse::<Cardinal>() {
+            return Ok(dir.into());
+        }
+        let word = s.to_lowercase().replace([' ', '-'], "");
+        Self::CLOCKWISE
+            .iter()
+            .copied()
+            .find(|dir| {
+                dir.compass().eq_ignore_ascii_case(&word) || dir.name().replace('-', "") == word
+            })
+            .ok_or_else(|| anyhow!("invalid direction: {}", s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate() {
+        for dir in Direction::iter().copied() {
+            assert_eq!(dir.turn_45_deg().turn_left_45_deg(), dir);
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.rotate(8), dir);
+            assert_eq!(dir.rotate(-3), dir.rotate(5));
+            assert_eq!(dir.opposite().vector(), -dir.vector());
+        }
+        assert_eq!(Direction::NW.turn_45_deg(), Direction::N);
+        assert_eq!(Direction::N.turn_left_45_deg(), Direction::NW);
+        assert_eq!(Direction::SE.turn_right(), Direction::SW);
+
+        for dir in Cardinal::iter().copied() {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.opposite().vector(), -dir.vector());
+            assert_eq!(Direction::from(dir).turn_right(), dir.turn_right().into());
+        }
+        assert_eq!(Cardinal::W.rotate(1), Cardinal::N);
+        assert_eq!(Cardinal::N.rotate(-5), Cardinal::W);
+    }
+
+    #[test]
+    fn test_symbols() {
+        for dir in Cardinal::iter().copied() {
+            assert_eq!(Cardinal::from_symbol(dir.symbol()), Some(dir));
+            assert_eq!(Cardinal::from_symbol(dir.letter()), Some(dir));
+            assert_eq!(dir.to_string().parse::<Cardinal>().unwrap(), dir);
+            assert_eq!(Direction::from(dir).cardinal(), Some(dir));
+        }
+        for dir in Direction::iter().copied() {
+            assert_eq!(Direction::from_symbol(dir.arrow()), Some(dir));
+            assert_eq!(dir.compass().parse::<Direction>().unwrap(), dir);
+            assert_eq!(dir.to_string().parse::<Direction>().unwrap(), dir);
+        }
+        assert_eq!("North East".parse::<Direction>().unwrap(), Direction::NE);
+        assert_eq!("sw".parse::<Direction>().unwrap(), Direction::SW);
+        assert_eq!("Up".parse::<Cardinal>().unwrap(), Cardinal::N);
+        assert_eq!(Direction::from_symbol('<'), Some(Direction::W));
+        assert!("x".parse::<Cardinal>().is_err());
+        assert!("NE".parse::<Cardinal>().is_err());
+        assert!("upward".parse::<Direction>().is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use crate::input::crate_path;
+use crate::runner::parse;
+use crate::{Part, Solver};
+use anyhow::*;
+use std::fs;
+use std::io::ErrorKind;
+use std::result::Result::Ok;
+
+pub const EXAMPLES_DIR: &str = "examples";
+
+/// Example input from the puzzle description together with the expected answers.
+///
+/// Examples live in `examples/<day>/<name>.txt`, next to a `<name>.answers` file with
+/// `part1: <answer>` and `part2: <answer>` lines. An `ignore: <part>` line marks a part whose
+/// solution is known to disagree with the example. Lines starting with `#` are comments.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub ignored: Vec<Part>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn is_ignored(&self, part: Part) -> bool {
+        self.ignored.contains(&part)
+    }
+
+    /// Solves the example and compares the answer with the expected one, if there is any.
+    pub fn check(&self, solver: &dyn Solver, part: Part) -> Result<()> {
+        let Some(expected) = self.expected(part) else {
+            return Ok(());
+        };
+        let name = format!("{}/{}/{}.txt", EXAMPLES_DIR, solver.day(), self.name);
+        let input = parse(solver, &self.input, &name)?;
+        let answer = solver.solve(&input, part)?;
+        ensure!(answer == expected, "expected {}, got {}", expected, answer);
+        Ok(())
+    }
+
+    fn parse_answers(&mut self, answers: &str) -> Result<()> {
+        for (i, line) in answers.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once(':')
+                .with_context(|| format!("line {}: expected `key: value`", i + 1))?;
+            let value = value.trim().to_string();
+            match key.trim() {
+                "part1" => self.part1 = Some(value),
+                "part2" => self.part2 = Some(value),
+                "ignore" => self.ignored.push(value.parse()?),
+                key => bail!("line {}: unknown key {}", i + 1, key),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Loads the examples of a day, sorted by name. A day without an examples directory has none.
+pub fn load_examples(day: u8) -> Result<Vec<Example>> {
+    let dir = crate_path(EXAMPLES_DIR).join(day.to_string());
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err).with_context(|| format!("read {}", dir.display())),
+    };
+
+    let mut examples = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        let mut example = Example {
+            name: name.to_string(),
+            input: fs::read_to_string(&path).with_context(|| format!("read {}", path.display()))?,
+            ..Default::default()
+        };
+
+        let answers_path = path.with_extension("answers");
+        match fs::read_to_string(&answers_path) {
+            Ok(answers) => example
+                .parse_answers(&answers)
+                .with_context(|| format!("parse {}", answers_path.display()))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(err).with_context(|| format!("read {}", answers_path.display()))
+            }
+        }
+        examples.push(example);
+    }
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(examples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let mut example = Example::default();
+        example
+            .parse_answers("# comment\npart1: 10092\npart2: co,de,ka,ta\n\nignore: 2\n")
+            .unwrap();
+        assert_eq!(example.expected(Part::One), Some("10092"));
+        assert_eq!(example.expected(Part::Two), Some("co,de,ka,ta"));
+        assert!(!example.is_ignored(Part::One));
+        assert!(example.is_ignored(Part::Two));
+
+        assert!(example.parse_answers("part3: 1").is_err());
+    }
+}
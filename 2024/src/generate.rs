@@ -0,0 +1,216 @@
+use crate::rng::Rng;
+use crate::*;
+use anyhow::*;
+use std::result::Result::Ok;
+
+/// Seed of the generated inputs unless given.
+pub const DEFAULT_SEED: u64 = 2024;
+
+/// Synthetic input generator of a day, for seeing how the solutions scale beyond the real inputs.
+pub struct Generator {
+    pub day: u8,
+    /// What the size knob counts
+    pub unit: &'static str,
+    /// Size close to that of the real input
+    pub default_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    /// Generates an input of the given size, the same for the same seed.
+    pub fn input(&self, size: Option<usize>, seed: u64) -> Result<String> {
+        let size = size.unwrap_or(self.default_size);
+        ensure!(
+            size > 0,
+            "day {} needs at least one of its {}",
+            self.day,
+            self.unit
+        );
+        Ok((self.generate)(&mut Rng::new(seed), size))
+    }
+}
+
+pub static GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        unit: "location pairs",
+        default_size: 1000,
+        generate: Day01::generate,
+    },
+    Generator {
+        day: 2,
+        unit: "reports",
+        default_size: 1000,
+        generate: Day02::generate,
+    },
+    Generator {
+        day: 3,
+        unit: "instructions",
+        default_size: 700,
+        generate: Day03::generate,
+    },
+    Generator {
+        day: 4,
+        unit: "letters per side",
+        default_size: 140,
+        generate: Day04::generate,
+    },
+    Generator {
+        day: 5,
+        unit: "pages",
+        default_size: 49,
+        generate: Day05::generate,
+    },
+    Generator {
+        day: 6,
+        unit: "tiles per side",
+        default_size: 130,
+        generate: Day06::generate,
+    },
+    Generator {
+        day: 7,
+        unit: "equations",
+        default_size: 850,
+        generate: Day07::generate,
+    },
+    Generator {
+        day: 8,
+        unit: "tiles per side",
+        default_size: 50,
+        generate: Day08::generate,
+    },
+    Generator {
+        day: 9,
+        unit: "digits",
+        default_size: 19999,
+        generate: Day09::generate,
+    },
+    Generator {
+        day: 10,
+        unit: "tiles per side",
+        default_size: 50,
+        generate: Day10::generate,
+    },
+    Generator {
+        day: 11,
+        unit: "stones",
+        default_size: 8,
+        generate: Day11::generate,
+    },
+    Generator {
+        day: 12,
+        unit: "plots per side",
+        default_size: 140,
+        generate: Day12::generate,
+    },
+    Generator {
+        day: 13,
+        unit: "claw machines",
+        default_size: 320,
+        generate: Day13::generate,
+    },
+    Generator {
+        day: 14,
+        unit: "robots",
+        default_size: 500,
+        generate: Day14::generate,
+    },
+    Generator {
+        day: 15,
+        unit: "tiles per side",
+        default_size: 50,
+        generate: Day15::generate,
+    },
+    Generator {
+        day: 23,
+        unit: "computers",
+        default_size: 520,
+        generate: Day23::generate,
+    },
+    Generator {
+        day: 24,
+        unit: "bits",
+        default_size: 45,
+        generate: Day24::generate,
+    },
+    Generator {
+        day: 25,
+        unit: "schematics",
+        default_size: 500,
+        generate: Day25::generate,
+    },
+];
+
+pub fn find_generator(day: u8) -> Result<&'static Generator> {
+    GENERATORS
+        .iter()
+        .find(|generator| generator.day == day)
+        .ok_or_else(|| anyhow!("day {} has no input generator", day))
+}
+
+/// Parses a size such as `1000`, `1_000`, `10k` or `1M`.
+pub fn parse_size(s: &str) -> Result<usize> {
+    let s = s.replace('_', "");
+    let (digits, factor) = match s.strip_suffix(['k', 'K']) {
+        Some(digits) => (digits, 1_000),
+        None => match s.strip_suffix('M') {
+            Some(digits) => (digits, 1_000_000),
+            None => (s.as_str(), 1),
+        },
+    };
+    let size = digits
+        .parse::<usize>()
+        .with_context(|| format!("invalid size: {}", s))?;
+    size.checked_mul(factor)
+        .with_context(|| format!("size too large: {}", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Input;
+    use crate::runner::{find_solver, solve};
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("1000").unwrap(), 1000);
+        assert_eq!(parse_size("1_000_000").unwrap(), 1_000_000);
+        assert_eq!(parse_size("10k").unwrap(), 10_000);
+        assert_eq!(parse_size("2M").unwrap(), 2_000_000);
+        assert!(parse_size("M").is_err());
+        assert!(parse_size("-1").is_err());
+    }
+
+    #[test]
+    fn test_generators() {
+        for generator in GENERATORS {
+            let input = generator.input(Some(20), 7).unwrap();
+            assert_eq!(input, generator.input(Some(20), 7).unwrap());
+            assert_ne!(input, generator.input(Some(20), 8).unwrap());
+
+            assert_eq!(Input::new(&input).text(), input, "day {}", generator.day);
+
+            let solver = find_solver(generator.day).unwrap();
+            for size in [1, 2, 3, 20] {
+                let input = generator.input(Some(size), 7).unwrap();
+                let parsed = solver
+                    .parse_input(&input)
+                    .unwrap_or_else(|err| panic!("day {} size {}: {:?}", generator.day, size, err));
+                for part in Part::iter() {
+                    solve(solver, &parsed, part).unwrap();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lan_party_stays_planted() {
+        let solver = find_solver(23).unwrap();
+        for size in [1, 13, 14, 27, 100] {
+            let input = Day23::generate(&mut Rng::new(7), size);
+            let parsed = solver.parse_input(&input).unwrap();
+            let password = solve(solver, &parsed, Part::Two).unwrap().unwrap();
+            assert_eq!(password.split(',').count(), 13, "size {}", size);
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use crate::bounds::Bounds;
+use crate::input::Input;
+use crate::parser::ParseError;
+use crate::point::Point;
+use crate::*;
+use anyhow::*;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+use std::result::Result::Ok;
+
+/// Dense grid of cells stored row-major, indexed by `(row, column)` positions from the top left.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `height` rows of `width` cells, all set to `fill`.
+    pub fn new(height: usize, width: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; height * width],
+            height,
+            width,
+        }
+    }
+
+    /// Grid covering the positions from `(0, 0)` to the bottom right corner of the bounds.
+    pub fn from_bounds(bounds: &Bounds<usize>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let (height, width) = bounds.max;
+        Self::new(height + 1, width + 1, fill)
+    }
+
+    /// Grid covering the positions from `(0, 0)` to the bottom right corner of the rectangle.
+    pub fn from_area(area: &Rectangle, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_bounds(&(*area).into(), fill)
+    }
+
+    /// Grid covering the positions from `(0, 0)` to the bottom right corner of the rectangle,
+    /// `None` if the corner is negative.
+    pub fn from_absolute_area(area: &AbsoluteRectangle, fill: T) -> Option<Self>
+    where
+        T: Clone,
+    {
+        let (height, width) = area.1;
+        let max = (usize::try_from(height).ok()?, usize::try_from(width).ok()?);
+        Some(Self::from_bounds(&Bounds::new((0, 0), max), fill))
+    }
+
+    /// Grid of the given rows, which must all be as wide.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        ensure!(
+            rows.iter().all(|row| row.len() == width),
+            "rows of different widths"
+        );
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            height,
+            width,
+        })
+    }
+
+    /// Parses a grid of one cell per character, failing on characters `tile` does not map and
+    /// on rows of different widths.
+    pub fn parse<F: Fn(char) -> Option<T>>(
+        input: &Input,
+        tile: F,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let rows = input.grid_with(tile, expected)?;
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            height,
+            width,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Bounds of the positions of the grid, `None` when it is empty.
+    pub fn bounds(&self) -> Option<Bounds<usize>> {
+        (self.height > 0 && self.width > 0)
+            .then(|| Bounds::new((0, 0), (self.height - 1, self.width - 1)))
+    }
+
+    /// Rectangle of the positions of the grid, `((0, 0), (0, 0))` when it is empty.
+    pub fn area(&self) -> Rectangle {
+        self.bounds().map_or(((0, 0), (0, 0)), Rectangle::from)
+    }
+
+    /// [`Grid::area`] in absolute positions.
+    pub fn absolute_area(&self) -> AbsoluteRectangle {
+        let ((i0, j0), (i1, j1)) = self.area();
+        ((i0 as isize, j0 as isize), (i1 as isize, j1 as isize))
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.0 < self.height && pos.1 < self.width
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// Cell at an absolute position, `None` for negative positions.
+    pub fn get_abs(&self, pos: AbsolutePosition) -> Option<&T> {
+        self.get(Point::from(pos).try_into().ok()?)
+    }
+
+    /// Swaps the cells at two positions, panicking if either is outside the grid.
+    pub fn swap(&mut self, a: Position, b: Position) {
+        assert!(self.contains(a) && self.contains(b), "swap out of the grid");
+        self.cells
+            .swap(a.0 * self.width + a.1, b.0 * self.width + b.1);
+    }
+
+    /// Position one step from `pos` in the direction, if still in the grid.
+    pub fn step(&self, pos: Position, dir: impl Into<Direction>) -> Option<Position> {
+        leap(pos, dir, 1).filter(|next| self.contains(*next))
+    }
+
+    /// Neighbours sharing a side with `pos`, in the order of [`Cardinal::iter`].
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> + '_ {
+        Cardinal::iter()
+            .filter_map(move |dir| self.step(pos, *dir))
+            .map(|next| (next, &self[next]))
+    }
+
+    /// Neighbours sharing a side or a corner with `pos`, in the order of [`Direction::iter`].
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = (Position, &T)> + '_ {
+        Direction::iter()
+            .filter_map(move |dir| self.step(pos, *dir))
+            .map(|next| (next, &self[next]))
+    }
+
+    /// Positions and cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(k, cell)| ((k / width, k % width), cell))
+    }
+
+    /// First position holding the value, row by row.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Positions holding the value, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|i| self.row(i))
+    }
+
+    pub fn column(&self, j: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(j < self.width, "column out of the grid");
+        self.cells.iter().skip(j).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|j| self.column(j))
+    }
+
+    /// Grid of the same size with `f` applied to every cell.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos).expect("position out of the grid")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos).expect("position out of the grid")
+    }
+}
+
+/// Writes the cells row by row, each row followed by a newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse(&Input::new("ab#\nc#d\n"), Some, "").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid.area(), ((0, 0), (1, 2)));
+        assert_eq!(grid.to_string(), "ab#\nc#d\n");
+
+        let digits = Grid::parse(&Input::new("12\n3x\n"), |c| c.to_digit(10), "digit");
+        assert_eq!(
+            digits.unwrap_err().to_string(),
+            "line 2, column 2: expected digit, found \"x\""
+        );
+        assert_eq!(
+            Grid::from_rows(vec![vec![1], vec![2, 3]])
+                .unwrap_err()
+                .to_string(),
+            "rows of different widths"
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = sample();
+        assert_eq!(grid.get((1, 2)), Some(&'d'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get_abs((-1, 0)), None);
+        assert_eq!(grid.get_abs((1, 0)), Some(&'c'));
+
+        grid[(0, 0)] = 'z';
+        *grid.get_mut((1, 0)).unwrap() = 'y';
+        grid.swap((0, 1), (1, 2));
+        assert_eq!(grid.to_string(), "zd#\ny#b\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = sample();
+        assert_eq!(
+            grid.neighbours4((0, 1)).collect::<Vec<_>>(),
+            vec![((0, 2), &'#'), ((1, 1), &'#'), ((0, 0), &'a')]
+        );
+        assert_eq!(
+            grid.neighbours8((0, 0))
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1), (1, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_find_and_iterate() {
+        let grid = sample();
+        assert_eq!(grid.find(&'#'), Some((0, 2)));
+        assert_eq!(grid.find(&'x'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            vec![(0, 2), (1, 1)]
+        );
+
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b', '#'], &['c', '#', 'd']]
+        );
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ac", "b#", "#d"]
+        );
+        assert_eq!(grid.map(|c| *c == '#').find_all(&true).count(), 2);
+    }
+
+    #[test]
+    fn test_from_area() {
+        let grid = Grid::from_area(&((0, 0), (2, 3)), 0);
+        assert_eq!((grid.height(), grid.width()), (3, 4));
+        assert_eq!(grid.area(), ((0, 0), (2, 3)));
+
+        let grid = Grid::from_absolute_area(&((0, 0), (1, 1)), '.').unwrap();
+        assert_eq!(grid.absolute_area(), ((0, 0), (1, 1)));
+        assert!(Grid::from_absolute_area(&((0, 0), (-1, 1)), '.').is_none());
+    }
+}
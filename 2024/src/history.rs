@@ -0,0 +1,259 @@
+use crate::Part;
+use anyhow::*;
+use std::fmt::Display;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::result::Result::Ok;
+use std::str::FromStr;
+
+pub const HISTORY_FILE: &str = "submissions.txt";
+
+/// Verdict of the website on a submitted answer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too early after a wrong answer; nothing was checked
+    TooRecent {
+        wait: String,
+    },
+    /// The part is already solved or not unlocked yet; nothing was checked
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Reads the verdict out of the page returned for a submission.
+    pub fn parse(page: &str) -> Result<Self> {
+        let outcome = if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if page.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            let wait = page
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map_or("some time", |(wait, _)| wait);
+            Outcome::TooRecent {
+                wait: wait.to_string(),
+            }
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            let message = page
+                .split_once("<article>")
+                .and_then(|(_, rest)| rest.split_once("</article>"))
+                .map_or(page, |(message, _)| message);
+            bail!("unrecognized response: {}", message.trim());
+        };
+        Ok(outcome)
+    }
+
+    /// Whether the answer was checked, making the outcome worth recording.
+    pub fn is_final(&self) -> bool {
+        !matches!(self, Outcome::TooRecent { .. } | Outcome::WrongLevel)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooRecent { wait } => write!(f, "too recent, wait {}", wait),
+            Outcome::WrongLevel => write!(f, "already solved or locked"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "wrong" => Ok(Outcome::Wrong),
+            _ => bail!("invalid outcome: {}", s),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+/// Submitted answers with their outcomes, in submission order.
+///
+/// The file holds one tab-separated `day part outcome answer` record per line.
+#[derive(Debug, Default)]
+pub struct History {
+    submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err).with_context(|| format!("read {}", path.display())),
+        };
+        Self::parse(&contents).with_context(|| format!("parse {}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self> {
+        let mut submissions = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let [day, part, outcome, answer] = line.splitn(4, '\t').collect::<Vec<_>>()[..] else {
+                bail!("line {}: expected 4 tab-separated fields", i + 1);
+            };
+            submissions.push(Submission {
+                day: day
+                    .parse()
+                    .with_context(|| format!("line {}: invalid day {}", i + 1, day))?,
+                part: part.parse().with_context(|| format!("line {}", i + 1))?,
+                outcome: outcome.parse().with_context(|| format!("line {}", i + 1))?,
+                answer: answer.to_string(),
+            });
+        }
+        Ok(Self { submissions })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_string()).with_context(|| format!("write {}", path.display()))
+    }
+
+    pub fn submissions(&self, day: u8, part: Part) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.day == day && s.part == part)
+    }
+
+    /// Records the outcome of a submission, unless nothing was checked.
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, outcome: Outcome) {
+        if outcome.is_final() {
+            self.submissions.push(Submission {
+                day,
+                part,
+                outcome,
+                answer: answer.to_string(),
+            });
+        }
+    }
+
+    /// Fails if the answer is already known to be wrong, either because it was submitted before
+    /// or because it lies beyond a too-high or too-low bound, or if the part is already solved.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Result<()> {
+        let value = answer.parse::<i128>().ok();
+        for s in self.submissions(day, part) {
+            ensure!(
+                s.outcome != Outcome::Correct,
+                "day {} part {} is already solved with {}",
+                day,
+                part,
+                s.answer
+            );
+            ensure!(
+                s.answer != answer,
+                "{} was already submitted: {}",
+                answer,
+                s.outcome
+            );
+
+            let (Some(value), Ok(bound)) = (value, s.answer.parse::<i128>()) else {
+                continue;
+            };
+            match s.outcome {
+                Outcome::TooHigh => ensure!(value < bound, "{} is too high, {} was", answer, bound),
+                Outcome::TooLow => ensure!(value > bound, "{} is too low, {} was", answer, bound),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# day\tpart\toutcome\tanswer")?;
+        for s in self.submissions.iter() {
+            writeln!(f, "{}\t{}\t{}\t{}", s.day, s.part, s.outcome, s.answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_outcome() {
+        let page =
+            |message: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", message);
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            ))
+            .unwrap(),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer; your answer is too high."
+            ))
+            .unwrap(),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You gave an answer too recently. You have 38s left to wait."
+            ))
+            .unwrap(),
+            Outcome::TooRecent {
+                wait: "38s".to_string()
+            }
+        );
+        assert!(Outcome::parse(&page("Something else")).is_err());
+    }
+
+    #[test]
+    fn test_history_check() {
+        let mut history = History::default();
+        history.record(1, Part::One, "100", Outcome::TooHigh);
+        history.record(1, Part::One, "10", Outcome::TooLow);
+        history.record(1, Part::One, "42", Outcome::Wrong);
+        history.record(1, Part::One, "43", Outcome::WrongLevel);
+
+        let history = History::parse(&history.to_string()).unwrap();
+        assert_eq!(history.submissions(1, Part::One).count(), 3);
+        assert!(history.check(1, Part::One, "42").is_err());
+        assert!(history.check(1, Part::One, "100").is_err());
+        assert!(history.check(1, Part::One, "101").is_err());
+        assert!(history.check(1, Part::One, "9").is_err());
+        assert!(history.check(1, Part::One, "43").is_ok());
+        assert!(history.check(1, Part::Two, "101").is_ok());
+
+        let mut history = history;
+        history.record(1, Part::One, "43", Outcome::Correct);
+        assert!(history.check(1, Part::One, "44").is_err());
+    }
+}
@@ -0,0 +1,375 @@
+use crate::generate::{find_generator, parse_size, DEFAULT_SEED};
+use crate::parser::ParseError;
+use anyhow::*;
+use std::fmt::Display;
+use std::fs;
+use std::io::{ErrorKind, Read};
+use std::path::{Path, PathBuf};
+use std::result::Result::Ok;
+use std::str::FromStr;
+
+pub const INPUT_DIR: &str = "input";
+
+/// Environment variable holding the input source, overridden by `--input`.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Resolves a path relative to the crate root, so the binaries work from any directory.
+pub fn crate_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(path)
+}
+
+/// Where the puzzle inputs are read from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    /// Directory holding one `<day>.txt` file per day
+    Dir(PathBuf),
+    /// Single file, usable for one day only
+    File(PathBuf),
+    /// Standard input, usable for one day only
+    Stdin,
+    /// Synthetic input of each day's generator, written `gen[:SIZE[:SEED]]`, the default size
+    /// being close to that of the real input
+    Generated { size: Option<usize>, seed: u64 },
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Dir(crate_path(INPUT_DIR))
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        ensure!(!s.is_empty(), "empty input path");
+        let path = PathBuf::from(s);
+        if !path.exists() {
+            if let Some(spec) = s
+                .strip_prefix("gen")
+                .filter(|spec| spec.is_empty() || spec.starts_with(':'))
+            {
+                return parse_generated(spec);
+            }
+        }
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            _ if path.is_dir() => InputSource::Dir(path),
+            _ => InputSource::File(path),
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Dir(path) | InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Generated { size, seed } => match size {
+                Some(size) => write!(f, "gen:{}:{}", size, seed),
+                None => write!(f, "gen::{}", seed),
+            },
+        }
+    }
+}
+
+/// Parses the `[:SIZE[:SEED]]` following `gen`, an empty size meaning the default one.
+fn parse_generated(spec: &str) -> Result<InputSource> {
+    let mut parts = spec.split(':').skip(1);
+    let size = match parts.next() {
+        Some(size) if !size.is_empty() => Some(parse_size(size)?),
+        _ => None,
+    };
+    let seed = match parts.next() {
+        Some(seed) => seed
+            .parse()
+            .with_context(|| format!("invalid seed: {}", seed))?,
+        None => DEFAULT_SEED,
+    };
+    ensure!(
+        parts.next().is_none(),
+        "expected gen[:SIZE[:SEED]], found gen{}",
+        spec
+    );
+    Ok(InputSource::Generated { size, seed })
+}
+
+impl InputSource {
+    /// Whether the source holds the input of a single day only.
+    pub fn is_single(&self) -> bool {
+        !matches!(self, InputSource::Dir(_) | InputSource::Generated { .. })
+    }
+
+    /// Path of the input of a day, or `None` for stdin and generated inputs.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Dir(dir) => Some(dir.join(format!("{}.txt", day))),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin | InputSource::Generated { .. } => None,
+        }
+    }
+
+    /// Reads the input of a day, returning `None` if the input directory has no file for it or
+    /// there is no generator. A missing file given explicitly is an error.
+    pub fn read(&self, day: u8) -> Result<Option<String>> {
+        let path = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("read stdin")?;
+                return Ok(Some(input));
+            }
+            InputSource::Generated { size, seed } => {
+                let Ok(generator) = find_generator(day) else {
+                    return Ok(None);
+                };
+                return generator.input(*size, *seed).map(Some);
+            }
+            InputSource::Dir(_) | InputSource::File(_) => self.path(day).expect("input file"),
+        };
+        match fs::read_to_string(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(err)
+                if err.kind() == ErrorKind::NotFound && matches!(self, InputSource::Dir(_)) =>
+            {
+                Ok(None)
+            }
+            Err(err) => Err(err).with_context(|| format!("read {}", path.display())),
+        }
+    }
+
+    /// Describes where the input of a day comes from.
+    pub fn describe(&self, day: u8) -> String {
+        if let InputSource::Generated { size, seed } = self {
+            let size = size.or_else(|| find_generator(day).ok().map(|g| g.default_size));
+            return InputSource::Generated { size, seed: *seed }.to_string();
+        }
+        match self.path(day) {
+            Some(path) => path.display().to_string(),
+            None => self.to_string(),
+        }
+    }
+}
+
+/// How [`Input`] normalizes the text of an input, beyond line endings and the byte order mark.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Policy {
+    /// Strip spaces and tabs at the end of every line
+    pub trim_lines: bool,
+    /// Drop blank lines at the end of the input
+    pub trim_blank_lines: bool,
+}
+
+impl Policy {
+    pub const DEFAULT: Policy = Policy {
+        trim_lines: true,
+        trim_blank_lines: true,
+    };
+
+    /// Keeps whitespace as it is.
+    pub const RAW: Policy = Policy {
+        trim_lines: false,
+        trim_blank_lines: false,
+    };
+}
+
+impl Default for Policy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Text of a puzzle input, normalized per a [`Policy`].
+///
+/// `\r\n` and `\r` line endings become `\n`, a leading byte order mark is dropped and a
+/// non-empty text always ends with a newline.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Input {
+    text: String,
+    first_line: usize,
+}
+
+impl Input {
+    pub fn new(text: &str) -> Self {
+        Self::with_policy(text, Policy::DEFAULT)
+    }
+
+    pub fn with_policy(text: &str, policy: Policy) -> Self {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let mut lines = text
+            .lines()
+            .map(|line| match policy.trim_lines {
+                true => line.trim_end_matches([' ', '\t']),
+                false => line,
+            })
+            .collect::<Vec<_>>();
+        if policy.trim_blank_lines {
+            while lines.last().is_some_and(|line| line.trim().is_empty()) {
+                lines.pop();
+            }
+        }
+
+        let mut text = lines.join("\n");
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        Self {
+            text,
+            first_line: 0,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Index of the first line within the whole input, non-zero for sections.
+    pub fn first_line(&self) -> usize {
+        self.first_line
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'_> {
+        self.text.lines()
+    }
+
+    /// Lines with their index within the whole input, ready for [`ParseError::offset`].
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.lines()
+            .enumerate()
+            .map(|(i, line)| (self.first_line + i, line))
+    }
+
+    /// Blocks of lines separated by blank lines.
+    pub fn sections(&self) -> Vec<Input> {
+        let mut sections = Vec::new();
+        let mut section: Option<Input> = None;
+        for (i, line) in self.numbered_lines() {
+            if line.trim().is_empty() {
+                sections.extend(section.take());
+                continue;
+            }
+            let section = section.get_or_insert_with(|| Input {
+                text: String::new(),
+                first_line: i,
+            });
+            section.text.push_str(line);
+            section.text.push('\n');
+        }
+        sections.extend(section);
+        sections
+    }
+
+    /// Characters of the lines, which must all have the same width.
+    pub fn grid(&self) -> Result<Vec<Vec<char>>, ParseError> {
+        self.grid_with(Some, "")
+    }
+
+    /// Tiles of the lines, which must all have the same width, failing at the first character
+    /// that is not a tile.
+    pub fn grid_with<T, F: Fn(char) -> Option<T>>(
+        &self,
+        tile: F,
+        expected: &str,
+    ) -> Result<Vec<Vec<T>>, ParseError> {
+        let mut width = None;
+        self.numbered_lines()
+            .map(|(i, line)| {
+                let row = line
+                    .char_indices()
+                    .map(|(offset, c)| {
+                        tile(c).ok_or_else(|| ParseError::char_at(line, offset, expected).offset(i))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let width = *width.get_or_insert(row.len());
+                if row.len() < width {
+                    let expected = format!("{} columns", width);
+                    return Err(ParseError::at(line, line.len(), expected).offset(i));
+                } else if row.len() > width {
+                    let (offset, _) = line.char_indices().nth(width).unwrap_or_default();
+                    return Err(ParseError::at(line, offset, "end of line").offset(i));
+                }
+                Ok(row)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source() {
+        assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+        assert_eq!(
+            "nowhere/7.txt".parse::<InputSource>().unwrap(),
+            InputSource::File("nowhere/7.txt".into())
+        );
+
+        let source = InputSource::default();
+        assert!(!source.is_single());
+        assert_eq!(source.path(7), Some(crate_path("input/7.txt")));
+        assert_eq!(InputSource::Dir("nowhere".into()).read(7).unwrap(), None);
+        assert!(InputSource::File("nowhere/7.txt".into()).read(7).is_err());
+
+        let generated = |size, seed| InputSource::Generated { size, seed };
+        assert_eq!("gen".parse::<InputSource>().unwrap(), generated(None, 2024));
+        assert_eq!(
+            "gen:10k".parse::<InputSource>().unwrap(),
+            generated(Some(10_000), 2024)
+        );
+        assert_eq!("gen::7".parse::<InputSource>().unwrap(), generated(None, 7));
+        assert_eq!(
+            "gen:5:7".parse::<InputSource>().unwrap(),
+            generated(Some(5), 7)
+        );
+        assert!("gen:5:7:9".parse::<InputSource>().is_err());
+        assert!("gen:x".parse::<InputSource>().is_err());
+        assert_eq!(generated(None, 7).describe(9), "gen:19999:7");
+        assert_eq!(
+            generated(Some(5), 7).read(9).unwrap(),
+            generated(Some(5), 7).read(9).unwrap()
+        );
+        assert_eq!(generated(Some(5), 7).read(22).unwrap(), None);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let input = Input::new("\u{feff}#.#  \r\n.#.\r\n\r\nab\t\n\n \n");
+        assert_eq!(input.text(), "#.#\n.#.\n\nab\n");
+        assert_eq!(Input::new("12345").text(), "12345\n");
+        assert_eq!(Input::new("\n\n").text(), "");
+        assert_eq!(Input::with_policy("a \r\n\n", Policy::RAW).text(), "a \n\n");
+
+        let sections = input.sections();
+        assert_eq!(sections.len(), 2);
+        assert_eq!(
+            sections[1].numbered_lines().collect::<Vec<_>>(),
+            [(3, "ab")]
+        );
+        assert_eq!(
+            sections[0].grid().unwrap(),
+            [['#', '.', '#'], ['.', '#', '.']]
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let input = Input::new("123\n45\n");
+        let err = input.grid().unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, ""));
+
+        let err = Input::new("12\n345").grid().unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 3, "5"));
+
+        let err = Input::new("12\n3x")
+            .grid_with(|c| c.to_digit(10), "digit")
+            .unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 2, "digit")
+        );
+    }
+}
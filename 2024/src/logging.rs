@@ -0,0 +1,65 @@
+use crate::days;
+use anyhow::*;
+use regex::Regex;
+use std::io::IsTerminal;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::EnvFilter;
+
+/// Environment variable holding the log directives, overridden by `--log`.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Expands the day shorthand of log directives: `4=debug` selects the module of day 4 and
+/// `15[part2]=trace` the events of day 15 within its `parse`, `part1` or `part2` span. Other
+/// directives are kept as they are, see [`EnvFilter`] for their syntax.
+pub fn expand_directives(directives: &str) -> String {
+    let day = Regex::new(r"^(\d{1,2})(=.*)?$").expect("valid regex");
+    let stage = Regex::new(r"^(\d{1,2})\[(\w+)\](=.*)?$").expect("valid regex");
+    directives
+        .split(',')
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+        .map(|directive| {
+            if let Some(caps) = day.captures(directive) {
+                let level = caps.get(2).map_or("", |level| level.as_str());
+                format!("{}::day{:0>2}{}", days::TARGET, &caps[1], level)
+            } else if let Some(caps) = stage.captures(directive) {
+                let level = caps.get(3).map_or("", |level| level.as_str());
+                format!("[{}{{day={}}}]{}", &caps[2], &caps[1], level)
+            } else {
+                directive.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Sends diagnostics to stderr. Only warnings are shown unless directives enable more.
+pub fn init_logging(directives: Option<&str>) -> Result<()> {
+    let directives = expand_directives(directives.unwrap_or_default());
+    let filter = EnvFilter::builder()
+        .with_default_directive(LevelFilter::WARN.into())
+        .parse(&directives)
+        .with_context(|| format!("invalid log directives: {}", directives))?;
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .without_time()
+        .try_init()
+        .map_err(|err| anyhow!(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_directives() {
+        assert_eq!(
+            expand_directives("4=debug, 15[part2]=trace,info"),
+            "adv_code_2024::days::day04=debug,[part2{day=15}]=trace,info"
+        );
+        assert_eq!(expand_directives("6"), "adv_code_2024::days::day06");
+        assert_eq!(expand_directives("ureq=debug"), "ureq=debug");
+    }
+}
@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering::Relaxed};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Global allocator counting allocations on top of the system one. The `aoc` binary installs it
+/// and calls [`CountingAllocator::mark_installed`] when built with the `count-allocs` feature:
+///
+/// ```sh
+/// cargo run --release --features count-allocs -- all
+/// ```
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    /// Records that the allocator is the global one, turning [`track`] on. Counting alone does
+    /// not tell, as the allocator may also be called directly.
+    pub fn mark_installed(&self) {
+        INSTALLED.store(true, Relaxed);
+    }
+
+    fn allocated(&self, size: usize) {
+        ALLOCATIONS.fetch_add(1, Relaxed);
+        BYTES.fetch_add(size as u64, Relaxed);
+        let live = LIVE.fetch_add(size as u64, Relaxed) + size as u64;
+        PEAK.fetch_max(live, Relaxed);
+    }
+
+    fn freed(&self, size: usize) {
+        LIVE.fetch_sub(size as u64, Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.freed(layout.size());
+    }
+
+    /// Counts as a new allocation of the new size, freeing the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            self.freed(layout.size());
+            self.allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made while running a stage.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    /// Highest number of live bytes above those live when the stage started
+    pub peak_bytes: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.5 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    let units = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < units.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.1} {}", value, units[unit]),
+    }
+}
+
+/// Whether the counting allocator is installed, see [`CountingAllocator::mark_installed`].
+pub fn is_counting() -> bool {
+    INSTALLED.load(Relaxed)
+}
+
+/// Runs `f` and returns the allocations it made, or `None` if the counting allocator is not
+/// installed. The counters are global, so stages must not be tracked concurrently; allocations
+/// of threads `f` spawns or hands work to are counted too.
+pub fn track<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !is_counting() {
+        return (f(), None);
+    }
+
+    let (allocations, bytes, live) = (
+        ALLOCATIONS.load(Relaxed),
+        BYTES.load(Relaxed),
+        LIVE.load(Relaxed),
+    );
+    PEAK.store(live, Relaxed);
+    let value = f();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak_bytes: PEAK.load(Relaxed).saturating_sub(live),
+    };
+    (value, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+
+    #[test]
+    fn test_counting_allocator() {
+        let layout = Layout::array::<u64>(1000).unwrap();
+        let (allocations, bytes) = (ALLOCATIONS.load(Relaxed), BYTES.load(Relaxed));
+        unsafe {
+            let ptr = CountingAllocator.alloc(layout);
+            let ptr = CountingAllocator.realloc(ptr, layout, 16000);
+            CountingAllocator.dealloc(ptr, Layout::array::<u64>(2000).unwrap());
+        }
+        // Calling the allocator directly does not install it
+        assert!(!is_counting());
+        assert!(ALLOCATIONS.load(Relaxed) - allocations >= 2);
+        assert!(BYTES.load(Relaxed) - bytes >= 24000);
+    }
+}
@@ -0,0 +1,530 @@
+use itertools::Itertools;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Error of parsing an input, at a 1-based line and column.
+///
+/// The input name is usually attached by the runner, which knows where the text came from, see
+/// [`ParseError::with_input`]. [`ParseError::diagnostic`] renders the offending line with a caret
+/// under the column.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub input: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+    /// The whole offending line
+    pub text: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.input {
+            Some(input) => write!(f, "{}:{}:{}: ", input, self.line, self.column)?,
+            None => write!(f, "line {}, column {}: ", self.line, self.column)?,
+        }
+        write!(f, "expected {}, found ", self.expected)?;
+        if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "{:?}", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl ParseError {
+    /// Error at a byte offset of the text, found at the whitespace-delimited token there.
+    pub fn at(text: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let found = text[offset..]
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+        Self::spanning(text, offset, found.len(), expected)
+    }
+
+    /// Error at a single character at a byte offset of the text, as for grids.
+    pub fn char_at(text: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let len = text[offset..].chars().next().map_or(0, char::len_utf8);
+        Self::spanning(text, offset, len, expected)
+    }
+
+    /// Error at the text from a byte offset of the text, spanning `len` bytes.
+    pub fn spanning(text: &str, offset: usize, len: usize, expected: impl Into<String>) -> Self {
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = text[offset..].find('\n').map_or(text.len(), |i| offset + i);
+        Self {
+            input: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: text[offset..(offset + len).min(line_end)].to_string(),
+            text: text[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Moves the error down by the given number of lines.
+    pub fn offset(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Moves an error found in `line[start..]` to its place within the whole line.
+    pub fn within(mut self, line: &str, start: usize) -> Self {
+        self.column += line[..start].chars().count();
+        self.text = line.to_string();
+        self
+    }
+
+    /// Names the input the error was found in, such as its path.
+    pub fn with_input(mut self, input: impl Into<String>) -> Self {
+        self.input = Some(input.into());
+        self
+    }
+
+    /// Renders the error with the offending line and a caret under the column:
+    ///
+    /// ```text
+    /// error: expected isize, found "x"
+    ///  --> input/14.txt:2:5
+    ///   |
+    /// 2 | p=6,x v=-1,-3
+    ///   |     ^
+    /// ```
+    pub fn diagnostic(&self) -> String {
+        let found = if self.found.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("{:?}", self.found)
+        };
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let carets = "^".repeat(self.found.chars().count().max(1));
+        format!(
+            "error: expected {}, found {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.expected,
+            found,
+            gutter,
+            self.input.as_deref().unwrap_or("input"),
+            self.line,
+            self.column,
+            gutter,
+            number,
+            self.text,
+            gutter,
+            " ".repeat(self.column - 1),
+            carets
+        )
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    Literal(String),
+    Whitespace,
+    Field,
+}
+
+/// Text captured by a field of a pattern.
+#[derive(Clone, Debug)]
+pub struct Field<'a> {
+    text: &'a str,
+    source: &'a str,
+    offset: usize,
+    /// Whether the field ends the pattern, taking the rest of the line
+    last: bool,
+}
+
+impl Field<'_> {
+    pub fn parse<T: FromStr>(&self) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| {
+            // When the first word of a last field parses, the fault is the input after it
+            let word = self.text.split([' ', '\t']).next().unwrap_or_default();
+            if self.last && word.len() < self.text.len() && word.parse::<T>().is_ok() {
+                let trailing = self.text.len() - word.len();
+                ParseError::spanning(
+                    self.source,
+                    self.offset + word.len(),
+                    trailing,
+                    "end of line",
+                )
+            } else {
+                ParseError::at(self.source, self.offset, type_name::<T>())
+            }
+        })
+    }
+}
+
+/// Short name of a type for error messages, such as `isize`.
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Values of all the fields of a pattern, implemented for tuples of [`FromStr`] types.
+pub trait Fields: Sized {
+    const COUNT: usize;
+
+    fn from_fields(fields: &[Field]) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_fields {
+    ($count:expr; $($t:ident $i:tt),+) => {
+        impl<$($t: FromStr),+> Fields for ($($t,)+) {
+            const COUNT: usize = $count;
+
+            fn from_fields(fields: &[Field]) -> Result<Self, ParseError> {
+                Ok(($(fields[$i].parse::<$t>()?,)+))
+            }
+        }
+    };
+}
+
+impl_fields!(1; A 0);
+impl_fields!(2; A 0, B 1);
+impl_fields!(3; A 0, B 1, C 2);
+impl_fields!(4; A 0, B 1, C 2, D 3);
+impl_fields!(5; A 0, B 1, C 2, D 3, E 4);
+impl_fields!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_fields!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_fields!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Declarative line format such as `"p={},{} v={},{}"`.
+///
+/// Every `{}` is a field that captures the text up to whatever follows it in the pattern, a run
+/// of spaces matches any non-empty run of spaces or tabs and everything else must match
+/// literally, including `\n` in patterns of multi-line records.
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    tokens: Vec<Token>,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Self {
+        let mut tokens = Vec::new();
+        let mut literal = String::new();
+        let mut rest = pattern;
+        while let Some(c) = rest.chars().next() {
+            let token = if let Some(after) = rest.strip_prefix("{}") {
+                rest = after;
+                Token::Field
+            } else if c == ' ' {
+                rest = rest.trim_start_matches(' ');
+                Token::Whitespace
+            } else {
+                literal.push(c);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            };
+            if !literal.is_empty() {
+                tokens.push(Token::Literal(std::mem::take(&mut literal)));
+            }
+            assert!(
+                !(token == Token::Field && tokens.last() == Some(&Token::Field)),
+                "adjacent fields in pattern {:?}",
+                pattern
+            );
+            tokens.push(token);
+        }
+        if !literal.is_empty() {
+            tokens.push(Token::Literal(literal));
+        }
+        Self { tokens }
+    }
+
+    pub fn fields(&self) -> usize {
+        self.tokens.iter().filter(|t| **t == Token::Field).count()
+    }
+
+    /// Matches the whole text against the pattern, returning the text of every field.
+    pub fn captures<'a>(&self, text: &'a str) -> Result<Vec<Field<'a>>, ParseError> {
+        let mut fields = Vec::new();
+        let mut offset = 0;
+        for (i, token) in self.tokens.iter().enumerate() {
+            let rest = &text[offset..];
+            match token {
+                Token::Literal(literal) => {
+                    if !rest.starts_with(literal.as_str()) {
+                        return Err(ParseError::at(text, offset, format!("{:?}", literal)));
+                    }
+                    offset += literal.len();
+                }
+                Token::Whitespace => {
+                    let trimmed = rest.trim_start_matches([' ', '\t']);
+                    if trimmed.len() == rest.len() {
+                        return Err(ParseError::at(text, offset, "whitespace"));
+                    }
+                    offset += rest.len() - trimmed.len();
+                }
+                Token::Field => {
+                    let line_end = rest.find('\n').unwrap_or(rest.len());
+                    let end = match self.tokens.get(i + 1) {
+                        Some(Token::Literal(literal)) => {
+                            rest.find(literal.as_str()).filter(|end| *end <= line_end)
+                        }
+                        Some(Token::Whitespace) => rest[..line_end].find([' ', '\t']),
+                        _ => Some(line_end),
+                    }
+                    .unwrap_or(line_end);
+                    fields.push(Field {
+                        text: &rest[..end],
+                        source: text,
+                        offset,
+                        last: i + 1 == self.tokens.len(),
+                    });
+                    offset += end;
+                }
+            }
+        }
+
+        if offset < text.len() {
+            return Err(ParseError::at(text, offset, "end of line"));
+        }
+        Ok(fields)
+    }
+
+    /// Parses the text into the values of the fields.
+    pub fn parse<T: Fields>(&self, text: &str) -> Result<T, ParseError> {
+        assert_eq!(
+            self.fields(),
+            T::COUNT,
+            "pattern {:?} does not match the number of values",
+            self.tokens
+        );
+        T::from_fields(&self.captures(text)?)
+    }
+
+    /// Parses every non-empty line of the text.
+    pub fn parse_lines<T: Fields>(&self, text: &str) -> Result<Vec<T>, ParseError> {
+        non_empty_lines(text)
+            .map(|(i, line)| self.parse(line).map_err(|err| err.offset(i)))
+            .collect()
+    }
+}
+
+/// Types with a textual line format, see [`from_line!`](crate::from_line).
+pub trait FromLine: Sized {
+    fn from_line(line: &str) -> Result<Self, ParseError>;
+}
+
+/// Implements [`FromLine`] for a type from a [`Pattern`] and a constructor taking its fields:
+///
+/// ```
+/// # use adv_code_2024::from_line;
+/// struct Robot {
+///     position: (isize, isize),
+///     velocity: (isize, isize),
+/// }
+///
+/// from_line!(Robot, "p={},{} v={},{}", |px: isize, py: isize, vx: isize, vy: isize| Robot {
+///     position: (px, py),
+///     velocity: (vx, vy),
+/// });
+/// ```
+#[macro_export]
+macro_rules! from_line {
+    ($ty:ty, $pattern:expr, |$($field:ident: $field_ty:ty),+ $(,)?| $body:expr) => {
+        impl $crate::parser::FromLine for $ty {
+            fn from_line(line: &str) -> std::result::Result<Self, $crate::parser::ParseError> {
+                static PATTERN: std::sync::LazyLock<$crate::parser::Pattern> =
+                    std::sync::LazyLock::new(|| $crate::parser::Pattern::new($pattern));
+                let ($($field,)+): ($($field_ty,)+) = PATTERN.parse(line)?;
+                std::result::Result::Ok($body)
+            }
+        }
+    };
+}
+
+fn non_empty_lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+}
+
+/// Parses every non-empty line of the text.
+pub fn parse_lines<T: FromLine>(text: &str) -> Result<Vec<T>, ParseError> {
+    non_empty_lines(text)
+        .map(|(i, line)| T::from_line(line).map_err(|err| err.offset(i)))
+        .collect()
+}
+
+/// Parses every group of lines separated by blank lines, as for multi-line patterns.
+pub fn parse_records<T: FromLine>(text: &str) -> Result<Vec<T>, ParseError> {
+    let lines = text.lines().collect_vec();
+    lines
+        .split(|line| line.trim().is_empty())
+        .scan(0, |start, record| {
+            let first = *start;
+            *start += record.len() + 1;
+            Some((first, record))
+        })
+        .filter(|(_, record)| !record.is_empty())
+        .map(|(first, record)| T::from_line(&record.join("\n")).map_err(|err| err.offset(first)))
+        .collect()
+}
+
+/// Parses the items of a line separated by a character, such as `7 6 4 2 1` or `75,47,61`.
+pub fn parse_list<T: FromStr>(line: &str, separator: char) -> Result<Vec<T>, ParseError> {
+    let mut offset = 0;
+    line.split(separator)
+        .map(|item| {
+            let start = offset;
+            offset += item.len() + separator.len_utf8();
+            item.parse()
+                .map_err(|_| ParseError::spanning(line, start, item.len(), type_name::<T>()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Robot {
+        position: (isize, isize),
+        velocity: (isize, isize),
+    }
+
+    crate::from_line!(
+        Robot,
+        "p={},{} v={},{}",
+        |px: isize, py: isize, vx: isize, vy: isize| Robot {
+            position: (px, py),
+            velocity: (vx, vy),
+        }
+    );
+
+    #[derive(Debug, PartialEq)]
+    struct Machine {
+        a: (usize, usize),
+        prize: (usize, usize),
+    }
+
+    crate::from_line!(
+        Machine,
+        "Button A: X+{}, Y+{}\nPrize: X={}, Y={}",
+        |ax: usize, ay: usize, px: usize, py: usize| Machine {
+            a: (ax, ay),
+            prize: (px, py),
+        }
+    );
+
+    fn error(line: usize, column: usize, expected: &str, found: &str, text: &str) -> ParseError {
+        ParseError {
+            input: None,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+            text: text.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_pattern() {
+        let pattern = Pattern::new("{}   {}");
+        assert_eq!(pattern.parse::<(u32, u32)>("3   4"), Ok((3, 4)));
+        assert_eq!(pattern.parse::<(u32, u32)>("3 \t4"), Ok((3, 4)));
+        assert_eq!(
+            pattern.parse::<(u32, u32)>("34"),
+            Err(error(1, 3, "whitespace", "", "34"))
+        );
+        assert_eq!(
+            pattern.parse_lines::<(u32, String)>("1 a\n\n2 b\n"),
+            Ok(vec![(1, "a".to_string()), (2, "b".to_string())])
+        );
+        assert_eq!(
+            Pattern::new("{}: {}").parse::<(String, String)>("190: 10 19"),
+            Ok(("190".to_string(), "10 19".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_from_line() {
+        assert_eq!(
+            parse_lines::<Robot>("p=0,4 v=3,-3\np=6,3 v=-1,-3\n"),
+            Ok(vec![
+                Robot {
+                    position: (0, 4),
+                    velocity: (3, -3)
+                },
+                Robot {
+                    position: (6, 3),
+                    velocity: (-1, -3)
+                },
+            ])
+        );
+        assert_eq!(
+            parse_lines::<Robot>("p=0,4 v=3,-3\np=6,x v=-1,-3\n"),
+            Err(error(2, 5, "isize", "x", "p=6,x v=-1,-3"))
+        );
+        assert_eq!(
+            parse_lines::<Robot>("q=0,4 v=3,-3"),
+            Err(error(1, 1, "\"p=\"", "q=0,4", "q=0,4 v=3,-3"))
+        );
+        assert_eq!(
+            parse_lines::<Robot>("p=0,4 v=3,-3 w"),
+            Err(error(1, 13, "end of line", " w", "p=0,4 v=3,-3 w"))
+        );
+        assert_eq!(
+            parse_lines::<Robot>("p=0,4 v=3,x w"),
+            Err(error(1, 11, "isize", "x", "p=0,4 v=3,x w"))
+        );
+        assert_eq!(
+            parse_lines::<Robot>("p=0,4 v=3,"),
+            Err(error(1, 11, "isize", "", "p=0,4 v=3,"))
+        );
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let err = parse_lines::<Robot>("p=0,4 v=3,-3\np=6,x v=-1,-3\n")
+            .unwrap_err()
+            .with_input("input/14.txt");
+        assert_eq!(
+            err.to_string(),
+            "input/14.txt:2:5: expected isize, found \"x\""
+        );
+        assert_eq!(
+            err.diagnostic(),
+            "error: expected isize, found \"x\"\n \
+             --> input/14.txt:2:5\n  |\n2 | p=6,x v=-1,-3\n  |     ^"
+        );
+
+        let err = parse_list::<u8>("7 6 400 2", ' ').unwrap_err();
+        assert_eq!((err.column, err.found.as_str()), (5, "400"));
+        assert!(err.diagnostic().ends_with("\n  |     ^^^"));
+
+        let err = ParseError::char_at("..#..\n.#X#.", 8, "tile");
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, ".#X#."));
+    }
+
+    #[test]
+    fn test_parse_records() {
+        let text = "Button A: X+94, Y+34\nPrize: X=8400, Y=5400\n\n\
+                    Button A: X+26, Y+66\nPrize: X=12748, Y=12176\n";
+        assert_eq!(
+            parse_records::<Machine>(text),
+            Ok(vec![
+                Machine {
+                    a: (94, 34),
+                    prize: (8400, 5400)
+                },
+                Machine {
+                    a: (26, 66),
+                    prize: (12748, 12176)
+                },
+            ])
+        );
+        assert_eq!(
+            parse_records::<Machine>(&text.replace("Y=12176", "Z=12176")),
+            Err(error(5, 17, "\"Y=\"", "Z=12176", "Prize: X=12748, Z=12176"))
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{AbsolutePosition, Position};
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Signed point on the plane, stored as a row and a column like [`Position`], growing down and
+/// to the right. Puzzles giving `x, y` coordinates use [`Point::from_xy`] and [`Point::x`] and
+/// [`Point::y`], `x` being the column and `y` the row.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    row: isize,
+    col: isize,
+}
+
+/// Displacement between two points, as returned by subtracting them.
+pub type Vector = Point;
+
+impl Point {
+    pub const ZERO: Point = Point::new(0, 0);
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    pub const fn from_xy(x: isize, y: isize) -> Self {
+        Self { row: y, col: x }
+    }
+
+    pub const fn row(self) -> isize {
+        self.row
+    }
+
+    pub const fn col(self) -> isize {
+        self.col
+    }
+
+    pub const fn x(self) -> isize {
+        self.col
+    }
+
+    pub const fn y(self) -> isize {
+        self.row
+    }
+}
+
+impl From<AbsolutePosition> for Point {
+    fn from((row, col): AbsolutePosition) -> Self {
+        Self::new(row, col)
+    }
+}
+
+impl From<Point> for AbsolutePosition {
+    fn from(point: Point) -> Self {
+        (point.row, point.col)
+    }
+}
+
+impl TryFrom<Position> for Point {
+    type Error = TryFromIntError;
+
+    fn try_from((row, col): Position) -> Result<Self, Self::Error> {
+        Ok(Self::new(row.try_into()?, col.try_into()?))
+    }
+}
+
+/// Fails for points with a negative coordinate.
+impl TryFrom<Point> for Position {
+    type Error = TryFromIntError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok((point.row.try_into()?, point.col.try_into()?))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Vector) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Vector) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Vector) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Point::new(3, 4), Point::new(5, 5));
+        assert_eq!(b - a, Point::new(2, 1));
+        assert_eq!(a - (b - a), Point::new(1, 3));
+        assert_eq!(a + (b - a) * 3, Point::new(9, 7));
+        assert_eq!(-a, Point::new(-3, -4));
+
+        let mut c = a;
+        c += b;
+        c -= Point::new(1, 1);
+        assert_eq!(c, Point::new(7, 8));
+    }
+
+    #[test]
+    fn test_coordinates() {
+        let point = Point::from_xy(2, -3);
+        assert_eq!((point.x(), point.y()), (2, -3));
+        assert_eq!((point.row(), point.col()), (-3, 2));
+        assert_eq!(AbsolutePosition::from(point), (-3, 2));
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Point::try_from((1_usize, 2)), Ok(Point::new(1, 2)));
+        assert!(Point::try_from((usize::MAX, 0)).is_err());
+        assert_eq!(Position::try_from(Point::new(1, 2)), Ok((1, 2)));
+        assert!(Position::try_from(Point::new(0, -1)).is_err());
+    }
+}
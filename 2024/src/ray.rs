@@ -0,0 +1,82 @@
+use crate::{leap, rectangle_includes, Direction, Position, Rectangle};
+
+/// Positions from `start` on in a direction, see [`ray`].
+#[derive(Clone, Debug)]
+pub struct Ray {
+    next: Option<Position>,
+    dir: Direction,
+    step: usize,
+    area: Rectangle,
+    remaining: usize,
+}
+
+/// Iterates lazily over the positions from `start` in the direction, one tile apart, until the
+/// ray leaves the area. Nothing is yielded if `start` is not in the area.
+pub fn ray(start: Position, dir: impl Into<Direction>, area: Rectangle) -> Ray {
+    Ray {
+        next: Some(start),
+        dir: dir.into(),
+        step: 1,
+        area,
+        remaining: usize::MAX,
+    }
+}
+
+impl Ray {
+    /// Yields positions `step` tiles apart instead. A step of 0 repeats the start.
+    pub fn with_step(self, step: usize) -> Self {
+        Self { step, ..self }
+    }
+
+    /// Stops after `len` positions, the start included.
+    pub fn with_max_len(self, len: usize) -> Self {
+        Self {
+            remaining: len,
+            ..self
+        }
+    }
+}
+
+impl Iterator for Ray {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Position> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let pos = self
+            .next
+            .filter(|pos| rectangle_includes(&self.area, *pos))?;
+        self.remaining -= 1;
+        self.next = leap(pos, self.dir, self.step);
+        Some(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cardinal;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_ray() {
+        let area = ((1, 1), (4, 5));
+        assert_eq!(
+            ray((2, 2), Direction::E, area).collect_vec(),
+            vec![(2, 2), (2, 3), (2, 4), (2, 5)]
+        );
+        assert_eq!(
+            ray((4, 5), Cardinal::N, area).with_step(2).collect_vec(),
+            vec![(4, 5), (2, 5)]
+        );
+        assert_eq!(
+            ray((2, 2), Direction::SE, area)
+                .with_max_len(2)
+                .collect_vec(),
+            vec![(2, 2), (3, 3)]
+        );
+        assert_eq!(ray((0, 0), Direction::NW, area).count(), 0);
+        assert_eq!(ray((1, 1), Direction::NW, area).collect_vec(), vec![(1, 1)]);
+    }
+}
@@ -0,0 +1,361 @@
+use crate::answers::{input_hash, Answers, Verdict, ANSWERS_FILE};
+use crate::bench::{bench_day, compare, BenchOptions, BenchReport};
+use crate::client::Client;
+use crate::examples::load_examples;
+use crate::history::{History, Outcome, HISTORY_FILE};
+use crate::input::{crate_path, InputSource, INPUT_ENV};
+use crate::logging::{init_logging, LOG_ENV};
+use crate::memory::{format_bytes, is_counting, track};
+use crate::parser::ParseError;
+use crate::{start_day, Parsed, Part, Solver, Unsolved, SOLVERS};
+use anyhow::*;
+use clap::Parser;
+use std::path::Path;
+use std::result::Result::Ok;
+use std::time::{Duration, Instant};
+
+pub fn find_solver(day: u8) -> Result<&'static dyn Solver> {
+    SOLVERS
+        .iter()
+        .copied()
+        .find(|solver| solver.day() == day)
+        .ok_or_else(|| anyhow!("day {} is not solved", day))
+}
+
+/// Reads the input of a day, failing if there is none.
+pub fn read_input(day: u8, source: &InputSource) -> Result<String> {
+    source
+        .read(day)?
+        .with_context(|| format!("input not found: {}", source.describe(day)))
+}
+
+/// Parses an input, naming it in parse errors.
+pub fn parse(solver: &dyn Solver, input: &str, name: &str) -> Result<Parsed> {
+    solver.parse_input(input).map_err(|mut err| {
+        if let Some(parse_error) = err.downcast_mut::<ParseError>() {
+            parse_error.input = Some(name.to_string());
+        }
+        err
+    })
+}
+
+/// Prints the offending line of a parse error with a caret under the column.
+pub fn report(err: &Error) {
+    if let Some(parse_error) = err.downcast_ref::<ParseError>() {
+        eprintln!("{}\n", parse_error.diagnostic());
+    }
+}
+
+/// Solves a single part, returning `None` if the part has no solution yet.
+pub fn solve(solver: &dyn Solver, input: &Parsed, part: Part) -> Result<Option<String>> {
+    match solver.solve(input, part) {
+        Ok(answer) => Ok(Some(answer)),
+        Err(err) if err.is::<Unsolved>() => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Checks and reports the examples of a part, returning `false` if any of them fails unexpectedly.
+pub fn check_examples(solver: &dyn Solver, part: Part) -> Result<bool> {
+    let mut all_passed = true;
+    for example in load_examples(solver.day())? {
+        if example.expected(part).is_none() {
+            continue;
+        }
+        match example.check(solver, part) {
+            Ok(()) => println!("Example {}: ok", example.name),
+            Err(err) if example.is_ignored(part) => {
+                println!("Example {}: {:#} (ignored)", example.name, err)
+            }
+            Err(err) => {
+                println!("Example {}: {:#}", example.name, err);
+                report(&err);
+                all_passed = false;
+            }
+        }
+    }
+    Ok(all_passed)
+}
+
+/// Runs the examples and, if the input is available, the real input of a day.
+pub fn run_day(solver: &dyn Solver, parts: &[Part], source: &InputSource) -> Result<()> {
+    let day = solver.day();
+    start_day(&day.to_string());
+
+    let answers = Answers::load(crate_path(ANSWERS_FILE))?;
+    let parsed = match source.read(day)? {
+        Some(input) => {
+            let start = Instant::now();
+            let (parsed, memory) = track(|| parse(solver, &input, &source.describe(day)));
+            let parsed = parsed.inspect_err(report)?;
+            println!("Parsed input in {:?}", start.elapsed());
+            if let Some(memory) = memory {
+                println!("Memory: {}", memory);
+            }
+            Some((input_hash(&input), parsed))
+        }
+        None => {
+            println!(
+                "Input not found at {}, skipping real input",
+                source.describe(day)
+            );
+            None
+        }
+    };
+
+    let mut failed_examples = Vec::new();
+    for part in parts.iter().copied() {
+        println!("\n=== Part {} ===", part);
+        if !check_examples(solver, part)? {
+            failed_examples.push(part);
+        }
+
+        let Some((hash, parsed)) = &parsed else {
+            continue;
+        };
+        let start = Instant::now();
+        let (answer, memory) = track(|| solve(solver, parsed, part));
+        let Some(answer) = answer? else {
+            println!("Not solved");
+            continue;
+        };
+        println!("Result = {}", answer);
+        println!("Took {:?}", start.elapsed());
+        if let Some(memory) = memory {
+            println!("Memory: {}", memory);
+        }
+
+        match answers.verify(day, part, hash, &answer) {
+            Verdict::Missing => {}
+            Verdict::Match => println!("Answer is confirmed"),
+            verdict => bail!("day {} part {}: {}", day, part, verdict),
+        }
+    }
+
+    ensure!(
+        failed_examples.is_empty(),
+        "examples of day {} failed for parts {:?}",
+        day,
+        failed_examples
+    );
+    Ok(())
+}
+
+/// Re-runs the given days against the recorded answers, returning `false` if any answer changed
+/// or any day failed. Days without input are skipped.
+pub fn verify(solvers: &[&dyn Solver], source: &InputSource) -> Result<bool> {
+    let answers = Answers::load(crate_path(ANSWERS_FILE))?;
+    let mut all_good = true;
+
+    for solver in solvers.iter().copied() {
+        let day = solver.day();
+        let input = match source.read(day) {
+            Ok(Some(input)) => Ok(input),
+            Ok(None) => {
+                println!("Day {:>2}: input not found, skipped", day);
+                continue;
+            }
+            Err(err) => Err(err),
+        };
+        let verified = input.and_then(|input| {
+            let hash = input_hash(&input);
+            let parsed = parse(solver, &input, &source.describe(day))?;
+            Part::iter()
+                .map(|part| {
+                    let answer = solve(solver, &parsed, part)?;
+                    let verdict = answer
+                        .as_ref()
+                        .map(|answer| answers.verify(day, part, &hash, answer));
+                    Ok((part, answer, verdict))
+                })
+                .collect::<Result<Vec<_>>>()
+        });
+
+        match verified {
+            Ok(results) => {
+                for (part, answer, verdict) in results {
+                    match (answer, verdict) {
+                        (Some(answer), Some(verdict)) => {
+                            all_good &= !matches!(verdict, Verdict::Changed { .. });
+                            println!("Day {:>2} part {}: {} ({})", day, part, verdict, answer);
+                        }
+                        _ => println!("Day {:>2} part {}: not solved", day, part),
+                    }
+                }
+            }
+            Err(err) => {
+                all_good = false;
+                println!("Day {:>2}: failed: {:#}", day, err);
+            }
+        }
+    }
+
+    Ok(all_good)
+}
+
+/// Records the current answers of a day as confirmed.
+pub fn confirm(solver: &dyn Solver, parts: &[Part], source: &InputSource) -> Result<()> {
+    let day = solver.day();
+    let answers_file = crate_path(ANSWERS_FILE);
+    let mut answers = Answers::load(&answers_file)?;
+    let input = read_input(day, source)?;
+    let hash = input_hash(&input);
+    let parsed = parse(solver, &input, &source.describe(day)).inspect_err(report)?;
+
+    for part in parts.iter().copied() {
+        let Some(answer) = solve(solver, &parsed, part)? else {
+            println!("Day {} part {} is not solved, nothing to record", day, part);
+            continue;
+        };
+        answers.insert(day, part, &hash, &answer);
+        println!("Recorded day {} part {}: {}", day, part, answer);
+    }
+
+    answers.save(answers_file)
+}
+
+/// Submits the answer of a part, computed from the input unless given, refusing answers the
+/// submission history already rules out. A correct answer is also recorded as confirmed.
+pub fn submit(
+    solver: &dyn Solver,
+    part: Part,
+    answer: Option<String>,
+    source: &InputSource,
+    client: &mut Client,
+) -> Result<()> {
+    let day = solver.day();
+    let input = source.read(day)?;
+    let answer = match (answer, &input) {
+        (Some(answer), _) => answer,
+        (None, Some(input)) => {
+            let parsed = parse(solver, input, &source.describe(day)).inspect_err(report)?;
+            solve(solver, &parsed, part)?
+                .with_context(|| format!("day {} part {} is not solved", day, part))?
+        }
+        (None, None) => bail!("input not found: {}", source.describe(day)),
+    };
+
+    let history_file = crate_path(HISTORY_FILE);
+    let mut history = History::load(&history_file)?;
+    history.check(day, part, &answer)?;
+
+    println!("Submitting day {} part {}: {}", day, part, answer);
+    let outcome = client.submit_answer(day, part, &answer)?;
+    println!("Outcome: {}", outcome);
+    history.record(day, part, &answer, outcome.clone());
+    history.save(&history_file)?;
+
+    if let (Outcome::Correct, Some(input)) = (outcome, input) {
+        let answers_file = crate_path(ANSWERS_FILE);
+        let mut answers = Answers::load(&answers_file)?;
+        answers.insert(day, part, &input_hash(&input), &answer);
+        answers.save(answers_file)?;
+        println!("Recorded day {} part {} as confirmed", day, part);
+    }
+    Ok(())
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+/// Benchmarks the given days, optionally saving the results and comparing them with a baseline.
+/// Days without input are skipped.
+pub fn bench(
+    solvers: &[&dyn Solver],
+    source: &InputSource,
+    options: BenchOptions,
+    save: Option<&Path>,
+    baseline: Option<&Path>,
+    threshold: f64,
+) -> Result<()> {
+    if cfg!(debug_assertions) {
+        println!("Warning: benchmarking a debug build, use --release for meaningful numbers\n");
+    }
+
+    let mut report = BenchReport::default();
+    print!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+        "Day", "Stage", "min", "median", "p95"
+    );
+    if is_counting() {
+        print!("  {:>10}  {:>10}  {:>10}", "allocs", "allocated", "peak");
+    }
+    println!();
+    for solver in solvers.iter().copied() {
+        let Some(input) = source.read(solver.day())? else {
+            println!("{:>3}  input not found, skipped", solver.day());
+            continue;
+        };
+        for m in bench_day(solver, &input, options)? {
+            print!(
+                "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}",
+                m.day,
+                m.stage.to_string(),
+                format_ns(m.stats.min_ns),
+                format_ns(m.stats.median_ns),
+                format_ns(m.stats.p95_ns)
+            );
+            if let Some(memory) = m.memory {
+                print!(
+                    "  {:>10}  {:>10}  {:>10}",
+                    memory.allocations,
+                    format_bytes(memory.bytes),
+                    format_bytes(memory.peak_bytes)
+                );
+            }
+            println!();
+            report.measurements.push(m);
+        }
+    }
+
+    if let Some(path) = save {
+        report.save(path)?;
+        println!("\nSaved results to {}", path.display());
+    }
+
+    if let Some(path) = baseline {
+        let baseline = BenchReport::load(path)?;
+        println!(
+            "\nComparison with {} (threshold {}%):",
+            path.display(),
+            threshold
+        );
+        for c in compare(&baseline, &report, threshold) {
+            println!(
+                "{:>3}  {:<6}  {:>10} -> {:>10}  {:>+7.1}%{}",
+                c.day,
+                c.stage.to_string(),
+                format_ns(c.baseline_ns),
+                format_ns(c.current_ns),
+                c.change,
+                if c.flagged { "  !" } else { "" }
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a single day of the Advent of Code 2024 solutions.
+#[derive(Parser, Debug)]
+struct DayCli {
+    /// Input file, directory of `<day>.txt` files, or `-` for stdin
+    #[arg(long, env = INPUT_ENV)]
+    input: Option<InputSource>,
+
+    /// Log directives, e.g. `debug`, `6=trace` or `15[part2]=trace`
+    #[arg(long, env = LOG_ENV)]
+    log: Option<String>,
+}
+
+/// Entry point of the per-day binaries.
+pub fn main(day: u8) -> Result<()> {
+    let cli = DayCli::parse();
+    init_logging(cli.log.as_deref())?;
+    run_day(
+        find_solver(day)?,
+        &[Part::One, Part::Two],
+        &cli.input.unwrap_or_default(),
+    )
+}
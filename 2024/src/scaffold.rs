@@ -0,0 +1,293 @@
+use crate::examples::EXAMPLES_DIR;
+use crate::input::{crate_path, INPUT_DIR};
+use anyhow::*;
+use itertools::Itertools;
+use regex::Regex;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::result::Result::Ok;
+use std::str::FromStr;
+
+pub(crate) const DAYS_DIR: &str = "src/days";
+const BIN_DIR: &str = "src/bin";
+const MAX_WIDTH: usize = 100;
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+const BIN_TEMPLATE: &str = include_str!("../templates/bin.rs.tmpl");
+
+/// Shape of a puzzle input, used to pick the parser skeleton of a new day.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum InputShape {
+    /// Rectangle of characters, one row per line
+    Grid,
+    /// Numbers on every line, separated by anything but digits
+    Numbers,
+    /// Groups of lines separated by blank lines
+    Sections,
+    /// Anything else, one string per line
+    Lines,
+}
+
+impl InputShape {
+    /// Guesses the shape of an input from a sample of it.
+    pub fn guess(sample: &str) -> Self {
+        let lines = sample.trim_end().lines().collect_vec();
+        if lines.is_empty() {
+            return InputShape::Lines;
+        }
+        if lines.iter().any(|line| line.trim().is_empty()) {
+            return InputShape::Sections;
+        }
+
+        let width = lines[0].chars().count();
+        if lines.len() > 1
+            && lines
+                .iter()
+                .all(|line| line.chars().count() == width && !line.chars().any(char::is_whitespace))
+        {
+            return InputShape::Grid;
+        }
+
+        if lines.iter().all(|line| {
+            line.chars().any(|c| c.is_ascii_digit()) && !line.chars().any(|c| c.is_alphabetic())
+        }) {
+            return InputShape::Numbers;
+        }
+        InputShape::Lines
+    }
+
+    fn input_type(self) -> &'static str {
+        match self {
+            InputShape::Grid => "Vec<Vec<char>>",
+            InputShape::Numbers => "Vec<Vec<i64>>",
+            InputShape::Sections => "Vec<Vec<String>>",
+            InputShape::Lines => "Vec<String>",
+        }
+    }
+
+    fn parse_template(self) -> &'static str {
+        match self {
+            InputShape::Grid => include_str!("../templates/parse_grid.rs.tmpl"),
+            InputShape::Numbers => include_str!("../templates/parse_numbers.rs.tmpl"),
+            InputShape::Sections => include_str!("../templates/parse_sections.rs.tmpl"),
+            InputShape::Lines => include_str!("../templates/parse_lines.rs.tmpl"),
+        }
+    }
+}
+
+impl Display for InputShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputShape::Grid => write!(f, "grid"),
+            InputShape::Numbers => write!(f, "numbers"),
+            InputShape::Sections => write!(f, "sections"),
+            InputShape::Lines => write!(f, "lines"),
+        }
+    }
+}
+
+impl FromStr for InputShape {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "grid" => Ok(InputShape::Grid),
+            "numbers" => Ok(InputShape::Numbers),
+            "sections" => Ok(InputShape::Sections),
+            "lines" => Ok(InputShape::Lines),
+            _ => bail!("invalid input shape: {}", s),
+        }
+    }
+}
+
+/// Renders the module of a new day from the template.
+pub fn render_day(day: u8, shape: InputShape) -> String {
+    DAY_TEMPLATE
+        .replace("{{DAY_PADDED}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+        .replace("{{INPUT_TYPE}}", shape.input_type())
+        .replace("{{PARSE}}\n", shape.parse_template())
+}
+
+/// Adds a day to the module declarations, re-exports and `SOLVERS` of `days/mod.rs`, keeping
+/// them sorted by day.
+pub fn register_day(mod_rs: &str, day: u8) -> Result<String> {
+    let padded = format!("{:02}", day);
+    let mut lines = mod_rs.lines().map(str::to_string).collect_vec();
+    for pattern in [
+        r"^(pub )?mod day(\d{2});$",
+        r"^pub use day(\d{2})::Day\d{2};$",
+    ] {
+        let re = Regex::new(pattern)?;
+        let existing = lines
+            .iter()
+            .enumerate()
+            .filter_map(|(i, line)| {
+                let caps = re.captures(line)?;
+                Some((i, caps.get(caps.len() - 1)?.as_str().to_string()))
+            })
+            .collect_vec();
+        let Some((first, first_day)) = existing.first().cloned() else {
+            bail!("no line matching {} in days/mod.rs", pattern);
+        };
+        ensure!(
+            existing.iter().all(|(_, d)| *d != padded),
+            "day {} is already registered",
+            day
+        );
+        let position = existing
+            .iter()
+            .find(|(_, d)| *d > padded)
+            .map(|(i, _)| *i)
+            .unwrap_or(existing[existing.len() - 1].0 + 1);
+        lines.insert(position, lines[first].replace(&first_day, &padded));
+    }
+    let contents = lines.join("\n") + "\n";
+
+    let re = Regex::new(r"(?s)(SOLVERS: &\[&dyn Solver\] = &\[)(.*?)(\];)")?;
+    let caps = re
+        .captures(&contents)
+        .context("no SOLVERS array in days/mod.rs")?;
+    let mut solvers = caps[2]
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect_vec();
+    solvers.push(format!("&Day{}", padded));
+    solvers.sort();
+
+    let mut array = String::from("\n");
+    let mut row = String::new();
+    for solver in solvers {
+        if !row.is_empty() && 4 + row.len() + 1 + solver.len() + 1 > MAX_WIDTH {
+            array += &format!("    {}\n", row);
+            row.clear();
+        }
+        if !row.is_empty() {
+            row.push(' ');
+        }
+        row += &solver;
+        row.push(',');
+    }
+    array += &format!("    {}\n", row);
+
+    let range = caps.get(2).unwrap().range();
+    Ok(format!(
+        "{}{}{}",
+        &contents[..range.start],
+        array,
+        &contents[range.end..]
+    ))
+}
+
+fn create(path: &Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("create {}", dir.display()))?;
+    }
+    fs::write(path, contents).with_context(|| format!("write {}", path.display()))
+}
+
+/// Generates a new day: its module, binary, an empty input and an example with a stub answers
+/// file. The sample, if given, becomes the example and picks the parser unless a shape is given.
+///
+/// Refuses to touch a day that already has code; existing input and example files are kept.
+/// Returns the created files.
+pub fn new_day(day: u8, sample: Option<&str>, shape: Option<InputShape>) -> Result<Vec<PathBuf>> {
+    ensure!((1..=25).contains(&day), "invalid day: {}", day);
+
+    let module = crate_path(DAYS_DIR).join(format!("day{:02}.rs", day));
+    let bin = crate_path(BIN_DIR).join(format!("{}.rs", day));
+    for path in [&module, &bin] {
+        ensure!(
+            !path.exists(),
+            "day {} already exists: {}",
+            day,
+            path.display()
+        );
+    }
+    let mod_rs = crate_path(DAYS_DIR).join("mod.rs");
+    let registry =
+        fs::read_to_string(&mod_rs).with_context(|| format!("read {}", mod_rs.display()))?;
+    let registry = register_day(&registry, day)?;
+
+    let shape = shape
+        .or(sample.map(InputShape::guess))
+        .unwrap_or(InputShape::Lines);
+    let examples = crate_path(EXAMPLES_DIR).join(day.to_string());
+    let files = [
+        (module, render_day(day, shape)),
+        (bin, BIN_TEMPLATE.replace("{{DAY}}", &day.to_string())),
+        (
+            crate_path(INPUT_DIR).join(format!("{}.txt", day)),
+            String::new(),
+        ),
+        (
+            examples.join("1.txt"),
+            sample.unwrap_or_default().to_string(),
+        ),
+        (
+            examples.join("1.answers"),
+            "# part1: \n# part2: \n".to_string(),
+        ),
+    ];
+
+    let mut created = Vec::new();
+    for (path, contents) in files {
+        if path.exists() {
+            continue;
+        }
+        create(&path, &contents)?;
+        created.push(path);
+    }
+    fs::write(&mod_rs, registry).with_context(|| format!("write {}", mod_rs.display()))?;
+    created.push(mod_rs);
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess_shape() {
+        assert_eq!(InputShape::guess("#..\n.#.\n..#\n"), InputShape::Grid);
+        assert_eq!(InputShape::guess("3   4\n4   3\n"), InputShape::Numbers);
+        assert_eq!(InputShape::guess("190: 10 19\n"), InputShape::Numbers);
+        assert_eq!(InputShape::guess("47|53\n\n75,47\n"), InputShape::Sections);
+        assert_eq!(InputShape::guess("x00 AND y00 -> z00\n"), InputShape::Lines);
+        assert_eq!(InputShape::guess(""), InputShape::Lines);
+    }
+
+    #[test]
+    fn test_register_day() {
+        let mod_rs = "\
+mod day01;
+mod day03;
+
+pub use day01::Day01;
+pub use day03::Day03;
+
+pub static SOLVERS: &[&dyn Solver] = &[&Day01, &Day03];
+";
+        let registered = register_day(mod_rs, 2).unwrap();
+        assert_eq!(
+            registered,
+            "\
+mod day01;
+mod day02;
+mod day03;
+
+pub use day01::Day01;
+pub use day02::Day02;
+pub use day03::Day03;
+
+pub static SOLVERS: &[&dyn Solver] = &[
+    &Day01, &Day02, &Day03,
+];
+"
+        );
+        assert!(register_day(&registered, 3).is_err());
+    }
+}
@@ -0,0 +1,343 @@
+use crate::grid::Grid;
+use crate::Position;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+#[derive(Clone, Debug)]
+struct Visit<N> {
+    distance: usize,
+    predecessors: Vec<N>,
+    paths: usize,
+}
+
+/// Nodes reached by a search from its start nodes, with their distances and the predecessors
+/// through which they are reached in as few steps or at as low a cost as possible.
+#[derive(Clone, Debug)]
+pub struct Search<N> {
+    visits: HashMap<N, Visit<N>>,
+    /// The goal [`astar`] stopped at, if reached
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Self {
+            visits: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Adds a start node, returning whether it is new.
+    fn start(&mut self, node: N) -> bool {
+        let visit = Visit {
+            distance: 0,
+            predecessors: Vec::new(),
+            paths: 1,
+        };
+        self.visits.insert(node, visit).is_none()
+    }
+
+    /// Records reaching `next` from `node` at `distance`. Returns whether it is the shortest
+    /// distance so far, so that `next` must be (re)visited.
+    fn reach(&mut self, node: &N, next: N, distance: usize) -> bool {
+        let paths = self.visits[node].paths;
+        match self.visits.get_mut(&next) {
+            Some(visit) if visit.distance < distance => false,
+            Some(visit) if visit.distance == distance => {
+                visit.predecessors.push(node.clone());
+                visit.paths += paths;
+                false
+            }
+            _ => {
+                let visit = Visit {
+                    distance,
+                    predecessors: vec![node.clone()],
+                    paths,
+                };
+                self.visits.insert(next, visit);
+                true
+            }
+        }
+    }
+
+    /// Shortest distance from the start nodes, `None` if not reached.
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.visits.get(node).map(|visit| visit.distance)
+    }
+
+    /// Reached nodes with their distances, in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, usize)> + '_ {
+        self.visits
+            .iter()
+            .map(|(node, visit)| (node, visit.distance))
+    }
+
+    /// Reached nodes, the start nodes included, in no particular order.
+    pub fn reached(&self) -> impl Iterator<Item = &N> + '_ {
+        self.visits.keys()
+    }
+
+    /// Nodes right before `node` on its shortest paths, the edges of the graph of all shortest
+    /// paths.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.visits
+            .get(node)
+            .map_or(&[], |visit| &visit.predecessors)
+    }
+
+    /// Number of distinct shortest paths from the start nodes to `node`, 0 if not reached.
+    pub fn path_count(&self, node: &N) -> usize {
+        self.visits.get(node).map_or(0, |visit| visit.paths)
+    }
+
+    /// One of the shortest paths to `node`, from a start node to `node` included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut visit = self.visits.get(node)?;
+        while let Some(previous) = visit.predecessors.first() {
+            path.push(previous.clone());
+            visit = &self.visits[previous];
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from the start nodes, each step to a neighbour counting 1.
+pub fn bfs<N, I, F>(starts: impl IntoIterator<Item = N>, mut neighbours: F) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.start(start.clone()) {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.visits[&node].distance + 1;
+        for next in neighbours(&node) {
+            if search.reach(&node, next.clone(), distance) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's search from the start nodes over neighbours given with the positive cost of the
+/// step to them.
+pub fn dijkstra<N, I, F>(starts: impl IntoIterator<Item = N>, neighbours: F) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+    F: FnMut(&N) -> I,
+{
+    best_first(starts, neighbours, |_| 0, |_| false)
+}
+
+/// A* search from `start` to the first node for which `is_goal` holds, stored in
+/// [`Search::goal`]. The heuristic must never overestimate the cost to the goal nor decrease by
+/// more than the cost of a step. Distances are exact for the nodes on the shortest paths to the
+/// goal, which are all counted, but not necessarily for the others.
+pub fn astar<N, I, F, G, H>(start: N, is_goal: G, neighbours: F, heuristic: H) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+    F: FnMut(&N) -> I,
+    G: Fn(&N) -> bool,
+    H: Fn(&N) -> usize,
+{
+    best_first([start], neighbours, heuristic, is_goal)
+}
+
+fn best_first<N, I, F, G, H>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: F,
+    heuristic: H,
+    is_goal: G,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+    F: FnMut(&N) -> I,
+    G: Fn(&N) -> bool,
+    H: Fn(&N) -> usize,
+{
+    let mut search = Search::new();
+    // Nodes are queued by estimated total cost, then by distance so that all the predecessors
+    // of a node on shortest paths are expanded before it and its path count is complete.
+    let mut queue = BinaryHeap::new();
+    let mut queued = Vec::new();
+    let mut settled = HashSet::new();
+    for start in starts {
+        if search.start(start.clone()) {
+            queue.push(Reverse((heuristic(&start), 0, queued.len())));
+            queued.push(start);
+        }
+    }
+
+    let mut bound = None;
+    while let Some(Reverse((estimate, distance, id))) = queue.pop() {
+        if bound.is_some_and(|bound| estimate > bound) {
+            break;
+        }
+        let node = queued[id].clone();
+        if search.visits[&node].distance < distance || !settled.insert(node.clone()) {
+            continue;
+        }
+        if search.goal.is_none() && is_goal(&node) {
+            search.goal = Some(node.clone());
+            bound = Some(estimate);
+        }
+
+        for (next, cost) in neighbours(&node) {
+            let next_distance = distance + cost;
+            if search.reach(&node, next.clone(), next_distance) {
+                queue.push(Reverse((
+                    next_distance + heuristic(&next),
+                    next_distance,
+                    queued.len(),
+                )));
+                queued.push(next);
+            }
+        }
+    }
+    search
+}
+
+/// Distance moving along rows and columns, the A* heuristic for steps to the 4 neighbours.
+pub fn manhattan(a: Position, b: Position) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+/// Distance moving diagonally too, the A* heuristic for steps to the 8 neighbours.
+pub fn chebyshev(a: Position, b: Position) -> usize {
+    a.0.abs_diff(b.0).max(a.1.abs_diff(b.1))
+}
+
+impl<T> Grid<T> {
+    /// [`bfs`] over the 4 neighbours of the tiles, stepping to those for which
+    /// `can_step(from, to)` holds.
+    pub fn bfs(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        mut can_step: impl FnMut(&T, &T) -> bool,
+    ) -> Search<Position> {
+        bfs(starts, |pos| {
+            let tile = &self[*pos];
+            self.neighbours4(*pos)
+                .filter(|(_, next)| can_step(tile, next))
+                .map(|(next_pos, _)| next_pos)
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// [`dijkstra`] over the 4 neighbours of the tiles, stepping at the cost `cost(from, to)`
+    /// gives, if any.
+    pub fn dijkstra(
+        &self,
+        starts: impl IntoIterator<Item = Position>,
+        mut cost: impl FnMut(&T, &T) -> Option<usize>,
+    ) -> Search<Position> {
+        dijkstra(starts, |pos| self.steps(*pos, &mut cost))
+    }
+
+    /// [`astar`] over the 4 neighbours of the tiles from `start` to `goal`, stepping at the cost
+    /// `cost(from, to)` gives, if any, which must be at least 1 for the [`manhattan`] heuristic.
+    pub fn astar(
+        &self,
+        start: Position,
+        goal: Position,
+        mut cost: impl FnMut(&T, &T) -> Option<usize>,
+    ) -> Search<Position> {
+        astar(
+            start,
+            |pos| *pos == goal,
+            |pos| self.steps(*pos, &mut cost),
+            |pos| manhattan(*pos, goal),
+        )
+    }
+
+    fn steps(
+        &self,
+        pos: Position,
+        cost: &mut impl FnMut(&T, &T) -> Option<usize>,
+    ) -> Vec<(Position, usize)> {
+        let tile = &self[pos];
+        self.neighbours4(pos)
+            .filter_map(|(next_pos, next)| Some((next_pos, cost(tile, next)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Input;
+
+    const MAZE: &str = "S..#\n.#..\n...#\n#..E\n";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(&Input::new(MAZE), Some, "").unwrap()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let maze = maze();
+        let search = maze.bfs([(0, 0)], |_, next| *next != '#');
+        assert_eq!(search.distance(&(3, 3)), Some(6));
+        assert_eq!(search.distance(&(0, 3)), None);
+        assert_eq!(search.path_count(&(3, 3)), 3);
+        assert_eq!(search.path_count(&(0, 3)), 0);
+        assert_eq!(search.predecessors(&(3, 3)), &[(3, 2)]);
+        assert_eq!(search.reached().count(), 12);
+
+        let path = search.path_to(&(3, 3)).unwrap();
+        assert_eq!((path[0], path.len()), ((0, 0), 7));
+        assert!(path.windows(2).all(|w| manhattan(w[0], w[1]) == 1));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Steps onto '.' cost 1 and onto '~' cost 5
+        let grid = Grid::parse(&Input::new("..~.\n.#~.\n....\n"), Some, "").unwrap();
+        let cost = |_: &char, next: &char| match next {
+            '.' => Some(1),
+            '~' => Some(5),
+            _ => None,
+        };
+
+        let search = grid.dijkstra([(0, 0)], cost);
+        assert_eq!(search.distance(&(0, 3)), Some(7));
+        assert_eq!(search.path_count(&(0, 3)), 2);
+        assert_eq!(search.distance(&(1, 2)), Some(9));
+
+        let search = grid.astar((0, 0), (0, 3), cost);
+        assert_eq!(search.goal, Some((0, 3)));
+        assert_eq!(search.distance(&(0, 3)), Some(7));
+        assert_eq!(search.path_count(&(0, 3)), 2);
+        assert_eq!(search.path_to(&(0, 3)).unwrap()[0], (0, 0));
+
+        let search = grid.astar((0, 0), (1, 1), cost);
+        assert_eq!(search.goal, None);
+    }
+
+    #[test]
+    fn test_generic_nodes() {
+        // Reaching 10 from 1 by doubling or adding one
+        let search = bfs([1_u32], |n| [n * 2, n + 1].into_iter().filter(|m| *m <= 10));
+        assert_eq!(search.distance(&10), Some(4));
+        assert_eq!(search.path_to(&10), Some(vec![1, 2, 4, 5, 10]));
+
+        let search = astar(1_u32, |n| *n == 10, |n| [(n * 2, 1), (n + 1, 1)], |_| 0);
+        assert_eq!(search.goal, Some(10));
+        assert_eq!(search.distance(&10), Some(4));
+        assert_eq!(chebyshev((0, 0), (3, 5)), 5);
+    }
+}
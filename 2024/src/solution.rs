@@ -0,0 +1,116 @@
+use crate::input::{Input, Policy};
+use anyhow::*;
+use serde::Serialize;
+use std::any::Any;
+use std::fmt::Display;
+use std::str::FromStr;
+use tracing::debug_span;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Part {
+    #[serde(rename = "1")]
+    One,
+    #[serde(rename = "2")]
+    Two,
+}
+
+impl Part {
+    pub fn iter() -> impl Iterator<Item = Part> {
+        [Part::One, Part::Two].into_iter()
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(anyhow!("invalid part: {}", s)),
+        }
+    }
+}
+
+/// Answer type and error of a part that has no solution yet.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not solved")
+    }
+}
+
+impl std::error::Error for Unsolved {}
+
+/// A day of the calendar: reads the puzzle input once and answers both parts from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Normalization of the input text before parsing.
+    const POLICY: Policy = Policy::DEFAULT;
+
+    fn parse(input: &Input) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+
+    fn part2(_input: &Self::Input) -> Result<Self::Part2> {
+        Err(Unsolved.into())
+    }
+}
+
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+/// Type-erased [`Solution`], so that the runner can keep every day in one registry.
+pub trait Solver: Send + Sync {
+    fn day(&self) -> u8;
+
+    fn parse_input(&self, input: &str) -> Result<Parsed>;
+
+    fn solve(&self, input: &Parsed, part: Part) -> Result<String>;
+}
+
+impl<S> Solver for S
+where
+    S: Solution + Send + Sync,
+    S::Input: Send + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Parsed> {
+        let _span = debug_span!("parse", day = S::DAY).entered();
+        Ok(Box::new(S::parse(&Input::with_policy(input, S::POLICY))?))
+    }
+
+    fn solve(&self, input: &Parsed, part: Part) -> Result<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .ok_or_else(|| anyhow!("input of day {} has unexpected type", S::DAY))?;
+        match part {
+            Part::One => {
+                let _span = debug_span!("part1", day = S::DAY).entered();
+                S::part1(input).map(|answer| answer.to_string())
+            }
+            Part::Two => {
+                let _span = debug_span!("part2", day = S::DAY).entered();
+                S::part2(input).map(|answer| answer.to_string())
+            }
+        }
+    }
+}
@@ -0,0 +1,355 @@
+use crate::answers::{input_hash, Answers, Verdict, ANSWERS_FILE};
+use crate::input::{crate_path, InputSource};
+use crate::memory::{format_bytes, is_counting, track, AllocStats};
+use crate::runner::{parse, solve};
+use crate::{Parsed, Part, Solver};
+use anyhow::*;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::result::Result::Ok;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "status", rename_all = "kebab-case")]
+pub enum Status {
+    Ok,
+    Missing,
+    Changed { expected: String },
+    Unsolved,
+    NoInput,
+    Failed { error: String },
+    Panicked { message: String },
+}
+
+impl Status {
+    /// Whether the status needs attention: a changed answer, an error or a panic.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Changed { .. } | Status::Failed { .. } | Status::Panicked { .. }
+        )
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Missing => write!(f, "missing"),
+            Status::Changed { expected } => write!(f, "changed, expected {}", expected),
+            Status::Unsolved => write!(f, "not solved"),
+            Status::NoInput => write!(f, "no input"),
+            Status::Failed { error } => write!(f, "failed: {}", error),
+            Status::Panicked { message } => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+impl From<Verdict> for Status {
+    fn from(verdict: Verdict) -> Self {
+        match verdict {
+            Verdict::Missing => Status::Missing,
+            Verdict::Match => Status::Ok,
+            Verdict::Changed { expected } => Status::Changed { expected },
+        }
+    }
+}
+
+/// Outcome of one part of one day.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Row {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<String>,
+    #[serde(flatten)]
+    pub status: Status,
+    pub time_ns: Option<u64>,
+    /// Allocations of parsing the input of the day, if they were counted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_memory: Option<AllocStats>,
+    /// Allocations of solving the part, if they were counted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<AllocStats>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    Table,
+    Json,
+    Tsv,
+}
+
+impl FromStr for Format {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "tsv" => Ok(Format::Tsv),
+            _ => bail!("invalid format: {}", s),
+        }
+    }
+}
+
+/// Runs `f`, turning an error or a panic into a status.
+fn catch<T, F: FnOnce() -> Result<T>>(f: F) -> Result<T, Status> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(err)) => Err(Status::Failed {
+            error: format!("{:#}", err),
+        }),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(Status::Panicked { message })
+        }
+    }
+}
+
+/// Parsed input of a day, with its hash and the allocations of parsing.
+type ParsedDay = (String, Parsed, Option<AllocStats>);
+
+fn solve_part(
+    solver: &dyn Solver,
+    part: Part,
+    parsed: &Result<ParsedDay, Status>,
+    answers: &Answers,
+) -> Row {
+    let day = solver.day();
+    let mut row = Row {
+        day,
+        part,
+        answer: None,
+        status: Status::Unsolved,
+        time_ns: None,
+        parse_memory: None,
+        memory: None,
+    };
+    let (hash, parsed, parse_memory) = match parsed {
+        Ok(parsed) => parsed,
+        Err(status) => {
+            row.status = status.clone();
+            return row;
+        }
+    };
+
+    let start = Instant::now();
+    let (answer, memory) = track(|| catch(|| solve(solver, parsed, part)));
+    row.time_ns = Some(start.elapsed().as_nanos() as u64);
+    match answer {
+        Ok(Some(answer)) => {
+            row.status = answers.verify(day, part, hash, &answer).into();
+            row.answer = Some(answer);
+            row.parse_memory = *parse_memory;
+            row.memory = memory;
+        }
+        Ok(None) => row.time_ns = None,
+        Err(status) => row.status = status,
+    }
+    row
+}
+
+/// Runs the given parts of the given days in parallel, one task per day and part, and verifies
+/// the answers. Errors and panics of a day are reported in its rows without stopping the others.
+/// When allocations are counted, the tasks run one at a time so that each gets its own counts.
+pub fn run_all(solvers: &[&dyn Solver], parts: &[Part], source: &InputSource) -> Result<Vec<Row>> {
+    let answers = Answers::load(crate_path(ANSWERS_FILE))?;
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(if is_counting() { 1 } else { 0 })
+        .build()?;
+
+    // Panics are reported in the summary, so keep the default hook from printing them
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let parsed = pool.install(|| {
+        solvers
+            .par_iter()
+            .map(|solver| {
+                let parsed = catch(|| {
+                    let Some(input) = source.read(solver.day())? else {
+                        return Ok(None);
+                    };
+                    let (parsed, memory) =
+                        track(|| parse(*solver, &input, &source.describe(solver.day())));
+                    Ok(Some((input_hash(&input), parsed?, memory)))
+                })
+                .and_then(|parsed| parsed.ok_or(Status::NoInput));
+                (*solver, parsed)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let mut rows = pool.install(|| {
+        parsed
+            .par_iter()
+            .flat_map(|(solver, parsed)| {
+                parts
+                    .par_iter()
+                    .map(|part| solve_part(*solver, *part, parsed, &answers))
+            })
+            .collect::<Vec<_>>()
+    });
+
+    panic::set_hook(hook);
+    rows.sort_by_key(|row| (row.day, row.part));
+    Ok(rows)
+}
+
+fn format_time(row: &Row) -> String {
+    row.time_ns
+        .map(|ns| format!("{:.2?}", Duration::from_nanos(ns)))
+        .unwrap_or_default()
+}
+
+/// Allocations, bytes allocated and peak live bytes, e.g. `120 / 1.5 MiB / 80.0 KiB`.
+fn format_memory(memory: Option<AllocStats>) -> String {
+    memory
+        .map(|m| {
+            format!(
+                "{} / {} / {}",
+                m.allocations,
+                format_bytes(m.bytes),
+                format_bytes(m.peak_bytes)
+            )
+        })
+        .unwrap_or_default()
+}
+
+fn tsv_memory(memory: Option<AllocStats>) -> String {
+    match memory {
+        Some(m) => format!("{}\t{}\t{}", m.allocations, m.bytes, m.peak_bytes),
+        None => "\t\t".to_string(),
+    }
+}
+
+/// Prints the rows as an aligned table, as JSON or as tab-separated values. The allocations
+/// columns are only printed if they were counted.
+pub fn print_summary(rows: &[Row], format: Format) -> Result<()> {
+    let counted = rows.iter().any(|row| row.memory.is_some());
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(rows)?),
+        Format::Tsv => {
+            print!("day\tpart\tanswer\tstatus\ttime_ns");
+            if counted {
+                print!("\tparse_allocations\tparse_bytes\tparse_peak_bytes");
+                print!("\tallocations\tbytes\tpeak_bytes");
+            }
+            println!();
+            for row in rows {
+                print!(
+                    "{}\t{}\t{}\t{}\t{}",
+                    row.day,
+                    row.part,
+                    row.answer.as_deref().unwrap_or_default(),
+                    row.status,
+                    row.time_ns.map(|ns| ns.to_string()).unwrap_or_default()
+                );
+                if counted {
+                    print!(
+                        "\t{}\t{}",
+                        tsv_memory(row.parse_memory),
+                        tsv_memory(row.memory)
+                    );
+                }
+                println!();
+            }
+        }
+        Format::Table => {
+            let answer_width = rows
+                .iter()
+                .filter_map(|row| row.answer.as_ref().map(String::len))
+                .fold("Answer".len(), usize::max);
+            let memory_width = |memory: fn(&Row) -> Option<AllocStats>, header: &str| {
+                rows.iter()
+                    .map(|row| format_memory(memory(row)).len())
+                    .fold(header.len(), usize::max)
+            };
+            let parse_width = memory_width(|row| row.parse_memory, "Parse memory");
+            let part_width = memory_width(|row| row.memory, "Memory");
+
+            print!(
+                "{:>3}  {:>4}  {:<answer_width$}  {:>10}  ",
+                "Day", "Part", "Answer", "Time"
+            );
+            if counted {
+                print!(
+                    "{:>parse_width$}  {:>part_width$}  ",
+                    "Parse memory", "Memory"
+                );
+            }
+            println!("Status");
+            for row in rows {
+                print!(
+                    "{:>3}  {:>4}  {:<answer_width$}  {:>10}  ",
+                    row.day,
+                    row.part.to_string(),
+                    row.answer.as_deref().unwrap_or("-"),
+                    format_time(row),
+                );
+                if counted {
+                    print!(
+                        "{:>parse_width$}  {:>part_width$}  ",
+                        format_memory(row.parse_memory),
+                        format_memory(row.memory)
+                    );
+                }
+                println!("{}", row.status);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| Ok(1)), Ok(1));
+        assert_eq!(
+            catch::<(), _>(|| bail!("bad input")),
+            Err(Status::Failed {
+                error: "bad input".to_string()
+            })
+        );
+
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let panicked = catch::<(), _>(|| panic!("player not found"));
+        panic::set_hook(hook);
+        assert_eq!(
+            panicked,
+            Err(Status::Panicked {
+                message: "player not found".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_row_json() {
+        let row = Row {
+            day: 6,
+            part: Part::Two,
+            answer: Some("1812".to_string()),
+            status: Status::Changed {
+                expected: "1813".to_string(),
+            },
+            time_ns: Some(1000),
+            parse_memory: None,
+            memory: None,
+        };
+        assert_eq!(
+            serde_json::to_string(&row).unwrap(),
+            r#"{"day":6,"part":"2","answer":"1812","status":"changed","expected":"1813","time_ns":1000}"#
+        );
+    }
+}
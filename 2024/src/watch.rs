@@ -0,0 +1,246 @@
+use crate::examples::EXAMPLES_DIR;
+use crate::input::{crate_path, InputSource};
+use crate::logging::LOG_ENV;
+use crate::scaffold::DAYS_DIR;
+use crate::Part;
+use anyhow::*;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::Command;
+use std::result::Result::Ok;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Interval between two checks of the watched files.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Target directory of the re-runs, so that building them neither waits for nor overwrites the
+/// running `aoc` binary.
+const TARGET_DIR: &str = "target/watch";
+
+#[derive(Clone, Debug)]
+pub struct WatchOptions {
+    pub parts: Vec<Part>,
+    pub release: bool,
+    pub log: Option<String>,
+}
+
+/// Files whose changes re-run a day: its module, `lib.rs`, its input and its examples directory.
+pub fn watched_paths(day: u8, source: &InputSource) -> Vec<PathBuf> {
+    let mut paths = vec![
+        crate_path(DAYS_DIR).join(format!("day{:02}.rs", day)),
+        crate_path("src/lib.rs"),
+        crate_path(EXAMPLES_DIR).join(day.to_string()),
+    ];
+    paths.extend(source.path(day));
+    paths
+}
+
+/// Modification times of the files and of the entries of the directories, `None` for those that
+/// do not exist, so that creating and deleting files counts as a change.
+fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut times = Vec::new();
+    for path in paths {
+        if let Ok(entries) = fs::read_dir(path) {
+            let mut entries = entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .collect::<Vec<_>>();
+            entries.sort();
+            times.extend(snapshot(&entries));
+        } else {
+            let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+            times.push((path.clone(), modified));
+        }
+    }
+    times
+}
+
+/// Paths that differ between two snapshots.
+fn changed(
+    before: &[(PathBuf, Option<SystemTime>)],
+    after: &[(PathBuf, Option<SystemTime>)],
+) -> Vec<PathBuf> {
+    let mut paths = after
+        .iter()
+        .filter(|entry| !before.contains(entry))
+        .chain(
+            before
+                .iter()
+                .filter(|(path, _)| !after.iter().any(|(p, _)| p == path)),
+        )
+        .map(|(path, _)| path.clone())
+        .collect::<Vec<_>>();
+    paths.dedup();
+    paths
+}
+
+/// Answers printed by a run of a day, taken from its `=== Part N ===` and `Result = ` lines.
+fn answers(output: &str) -> Vec<(Part, String)> {
+    let mut part = None;
+    let mut answers = Vec::new();
+    for line in output.lines() {
+        if let Some(header) = line.strip_prefix("=== Part ") {
+            part = header.trim_end_matches(" ===").parse().ok();
+        } else if let (Some(part), Some(answer)) = (part, line.strip_prefix("Result = ")) {
+            answers.push((part, answer.to_string()));
+        }
+    }
+    answers
+}
+
+/// Describes how the answers changed since the previous run, one line per part.
+fn diff(previous: &[(Part, String)], current: &[(Part, String)]) -> Vec<String> {
+    current
+        .iter()
+        .map(
+            |(part, answer)| match previous.iter().find(|(p, _)| p == part) {
+                None => format!("Part {}: {} (new)", part, answer),
+                Some((_, before)) if before == answer => {
+                    format!("Part {}: {} (unchanged)", part, answer)
+                }
+                Some((_, before)) => format!("Part {}: {} -> {}", part, before, answer),
+            },
+        )
+        .collect()
+}
+
+/// Builds and runs a day in a child `cargo run`, printing its output, and returns whether it
+/// succeeded together with the answers it printed. A run fails on a changed answer too.
+fn run(
+    day: u8,
+    source: &InputSource,
+    options: &WatchOptions,
+) -> Result<(bool, Vec<(Part, String)>)> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command.current_dir(crate_path("")).args([
+        "run",
+        "--quiet",
+        "--bin",
+        "aoc",
+        "--target-dir",
+        TARGET_DIR,
+    ]);
+    if options.release {
+        command.arg("--release");
+    }
+    command
+        .arg("--")
+        .arg(day.to_string())
+        .arg("--input")
+        .arg(source.to_string());
+    if let [part] = options.parts[..] {
+        command.arg("--part").arg(part.to_string());
+    }
+    if let Some(log) = &options.log {
+        command.env(LOG_ENV, log);
+    }
+
+    let output = command.output().context("run cargo")?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    print!("{}", stdout);
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+    Ok((output.status.success(), answers(&stdout)))
+}
+
+/// Re-runs the examples and the real input of a day whenever one of its [`watched_paths`]
+/// changes, showing how the answers changed. Build and run failures are reported and the
+/// watching goes on, until interrupted.
+pub fn watch(day: u8, source: &InputSource, options: WatchOptions) -> Result<()> {
+    ensure!(
+        !matches!(source, InputSource::Stdin),
+        "cannot watch stdin, use an input file"
+    );
+    let paths = watched_paths(day, source);
+    println!("Watching day {}:", day);
+    for path in &paths {
+        println!("  {}", path.display());
+    }
+
+    let mut previous = Vec::new();
+    let mut times = snapshot(&paths);
+    loop {
+        println!("\n--- Running day {} ---", day);
+        std::io::stdout().flush()?;
+        let (succeeded, answers) = run(day, source, &options)?;
+        if !answers.is_empty() {
+            println!("\n--- Answers ---");
+            for line in diff(&previous, &answers) {
+                println!("{}", line);
+            }
+            previous.retain(|(part, _)| !answers.iter().any(|(p, _)| p == part));
+            previous.extend(answers);
+        }
+        if !succeeded {
+            println!("\n--- Failed, waiting for changes ---");
+        }
+
+        let changes = loop {
+            thread::sleep(POLL_INTERVAL);
+            let current = snapshot(&paths);
+            let changes = changed(&times, &current);
+            times = current;
+            if !changes.is_empty() {
+                break changes;
+            }
+        };
+        for path in changes {
+            println!("\nChanged {}", path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let output = "Advent of Code 2024 - Day 06\nParsed input in 1ms\n\n=== Part 1 ===\n\
+            Example 1: ok\nResult = 5331\nTook 1ms\n\n=== Part 2 ===\nNot solved\n";
+        assert_eq!(answers(output), vec![(Part::One, "5331".to_string())]);
+    }
+
+    #[test]
+    fn test_diff() {
+        let answer = |part, answer: &str| (part, answer.to_string());
+        let previous = vec![answer(Part::One, "1"), answer(Part::Two, "2")];
+        assert_eq!(
+            diff(&previous, &[answer(Part::One, "1"), answer(Part::Two, "3")]),
+            vec!["Part 1: 1 (unchanged)", "Part 2: 2 -> 3"]
+        );
+        assert_eq!(
+            diff(&[], &[answer(Part::Two, "3")]),
+            vec!["Part 2: 3 (new)"]
+        );
+    }
+
+    #[test]
+    fn test_changed() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let (file, missing) = (dir.join("1.txt"), dir.with_extension("txt"));
+        let _ = fs::remove_file(&missing);
+        fs::write(&file, "1").unwrap();
+        let paths = vec![dir.clone(), missing.clone()];
+
+        let before = snapshot(&paths);
+        assert_eq!(before.len(), 2);
+        assert!(changed(&before, &snapshot(&paths)).is_empty());
+
+        fs::write(&missing, "2").unwrap();
+        fs::write(dir.join("2.txt"), "2").unwrap();
+        let after = snapshot(&paths);
+        assert_eq!(
+            changed(&before, &after),
+            vec![dir.join("2.txt"), missing.clone()]
+        );
+
+        fs::remove_file(&file).unwrap();
+        assert_eq!(changed(&after, &snapshot(&paths)), vec![file]);
+        fs::remove_dir_all(&dir).unwrap();
+        fs::remove_file(&missing).unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+//! Runs every example under `examples/` as a separate test case per part.
+
+use adv_code_2024::examples::load_examples;
+use adv_code_2024::{Part, SOLVERS};
+use libtest_mimic::{Arguments, Trial};
+
+fn main() {
+    let args = Arguments::from_args();
+
+    let mut trials = Vec::new();
+    for solver in SOLVERS.iter().copied() {
+        let day = solver.day();
+        let examples = load_examples(day).expect("load examples");
+        for example in examples {
+            for part in Part::iter() {
+                if example.expected(part).is_none() {
+                    continue;
+                }
+                let name = format!("day{:02}::example_{}::part{}", day, example.name, part);
+                let ignored = example.is_ignored(part);
+                let example = example.clone();
+                trials.push(
+                    Trial::test(name, move || Ok(example.check(solver, part)?))
+                        .with_ignored_flag(ignored),
+                );
+            }
+        }
+    }
+
+    libtest_mimic::run(&args, trials).exit();
+}
@@ -0,0 +1,50 @@
+//! Checks that the day and stage shorthands of the log directives select the events of a day,
+//! including those of its parallel work. Runs in its own process as it installs the global
+//! subscriber.
+
+use adv_code_2024::examples::load_examples;
+use adv_code_2024::logging::expand_directives;
+use adv_code_2024::{Part, SOLVERS};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::EnvFilter;
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_stage_directive_reaches_parallel_work() {
+    let capture = Capture::default();
+    let writer = capture.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::new(expand_directives("6[part2]=debug")))
+        .with_writer(move || writer.clone())
+        .with_ansi(false)
+        .finish();
+    tracing::subscriber::set_global_default(subscriber).unwrap();
+
+    let solver = SOLVERS.iter().find(|solver| solver.day() == 6).unwrap();
+    let example = &load_examples(6).unwrap()[0];
+    let input = solver.parse_input(&example.input).unwrap();
+    solver.solve(&input, Part::One).unwrap();
+    let answer = solver.solve(&input, Part::Two).unwrap();
+
+    let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+    let looping = output
+        .lines()
+        .filter(|line| line.contains("is looping"))
+        .count();
+    assert_eq!(looping.to_string(), answer);
+    assert!(output.lines().all(|line| line.contains("part2{day=6}")));
+}